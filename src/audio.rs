@@ -9,19 +9,53 @@
  * Heavily inspired by: https://github.com/TheBevyFlock/bevy_new_2d
  */
 
+pub(crate) mod director;
+
+use std::any::TypeId;
+
 use bevy::{
-    audio::{PlaybackMode, Volume},
+    audio::{DefaultSpatialScale, PlaybackMode, SpatialScale, Volume},
+    platform::collections::HashMap,
     prelude::*,
+    transform::TransformSystems,
 };
 
+use crate::characters::animations::AnimationState;
+
 pub(super) fn plugin(app: &mut App) {
-    // Apply global volume if it is changed
+    // Insert volume buses
+    // NOTE: Volumes are applied from `Settings` via `crate::settings`.
+    app.init_resource::<AudioBuses>();
+
+    // Add child plugins
+    app.add_plugins(director::plugin);
+
+    // Apply global and bus volume to new sinks and if volume is changed
+    app.add_systems(Update, apply_volume);
+
+    // Scale world units for spatial audio
+    app.insert_resource(DefaultSpatialScale(SpatialScale::new_2d(SPATIAL_SCALE)));
+
+    // Add message for spatial sounds and play them before their position is propagated
+    app.add_message::<PlaySpatialSound>();
     app.add_systems(
-        Update,
-        apply_global_volume.run_if(resource_changed::<GlobalVolume>),
+        PostUpdate,
+        play_spatial_sounds.before(TransformSystems::Propagate),
     );
 }
 
+/// Scale of world units for spatial audio
+///
+/// Volume is attenuated with the inverse of the scaled distance, so sounds within `1 / SPATIAL_SCALE`
+/// pixels are played at full volume.
+const SPATIAL_SCALE: f32 = 1. / 32.;
+/// Distance between the ears of [`SpatialListener`] in pixels
+pub(crate) const SPATIAL_EAR_GAP: f32 = 64.;
+/// Distance from [`SpatialListener`] in pixels beyond which spatial sounds are not played
+const SPATIAL_CULL_RADIUS: f32 = 240.;
+/// Maximum number of spatial sounds of the same [`SoundKind`] that play at the same time
+const MAX_CONCURRENT_SOUNDS: usize = 4;
+
 /// An organizational marker component that should be added to a spawned [`AudioPlayer`] if it's in the
 /// general "music" category (e.g. global background music, soundtrack).
 ///
@@ -38,6 +72,96 @@ pub(crate) struct Music;
 #[reflect(Component)]
 pub(crate) struct SoundEffect;
 
+/// An organizational marker component that should be added to a spawned [`AudioPlayer`] if it's in the
+/// general "UI" category (e.g. button hover and click sounds).
+///
+/// This can then be used to query for and operate on sounds in that category.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub(crate) struct UiSound;
+
+/// Linear factor that is applied to the volume of a single sink, e.g. for crossfades
+#[derive(Component, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Component)]
+pub(crate) struct Fade(pub(crate) f32);
+
+/// Bus that the volume of an audio category is controlled by
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub(crate) enum AudioBus {
+    Music,
+    SoundEffect,
+    Ui,
+}
+impl AudioBus {
+    /// All buses in the order they are displayed
+    pub(crate) const ALL: [Self; 3] = [Self::Music, Self::SoundEffect, Self::Ui];
+
+    /// Name that is displayed in settings
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Music => "Music",
+            Self::SoundEffect => "Sound Effects",
+            Self::Ui => "UI",
+        }
+    }
+}
+
+/// Volume and mute toggle of a single [`AudioBus`]
+#[derive(Clone, Copy, PartialEq, Debug, Reflect, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub(crate) struct BusVolume {
+    /// Linear volume of this bus
+    pub(crate) volume: f32,
+    pub(crate) muted: bool,
+}
+impl Default for BusVolume {
+    fn default() -> Self {
+        Self {
+            volume: 1.,
+            muted: false,
+        }
+    }
+}
+impl BusVolume {
+    /// Volume that is applied to sinks of this bus
+    fn effective(&self) -> Volume {
+        if self.muted {
+            Volume::SILENT
+        } else {
+            Volume::Linear(self.volume)
+        }
+    }
+}
+
+/// Volumes of all [`AudioBus`]es that are applied on top of [`GlobalVolume`]
+#[derive(
+    Resource, Default, Clone, PartialEq, Debug, Reflect, serde::Serialize, serde::Deserialize,
+)]
+#[reflect(Resource)]
+#[serde(default)]
+pub(crate) struct AudioBuses {
+    music: BusVolume,
+    sound_effect: BusVolume,
+    ui: BusVolume,
+}
+impl AudioBuses {
+    pub(crate) fn get(&self, bus: AudioBus) -> &BusVolume {
+        match bus {
+            AudioBus::Music => &self.music,
+            AudioBus::SoundEffect => &self.sound_effect,
+            AudioBus::Ui => &self.ui,
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, bus: AudioBus) -> &mut BusVolume {
+        match bus {
+            AudioBus::Music => &mut self.music,
+            AudioBus::SoundEffect => &mut self.sound_effect,
+            AudioBus::Ui => &mut self.ui,
+        }
+    }
+}
+
 /// A music audio instance.
 pub(crate) fn music(handle: Handle<AudioSource>) -> impl Bundle {
     (
@@ -51,17 +175,126 @@ pub(crate) fn music(handle: Handle<AudioSource>) -> impl Bundle {
     )
 }
 
-/// A sound effect audio instance.
-pub(crate) fn sound_effect(handle: Handle<AudioSource>) -> impl Bundle {
-    (AudioPlayer(handle), PlaybackSettings::DESPAWN, SoundEffect)
+/// A spatial sound effect audio instance at the given position.
+pub(crate) fn spatial_sound_effect(handle: Handle<AudioSource>, pos: Vec3) -> impl Bundle {
+    (
+        AudioPlayer(handle),
+        PlaybackSettings::DESPAWN.with_spatial(true),
+        Transform::from_translation(pos),
+        SoundEffect,
+    )
+}
+
+/// A UI sound audio instance.
+pub(crate) fn ui_sound(handle: Handle<AudioSource>) -> impl Bundle {
+    (AudioPlayer(handle), PlaybackSettings::DESPAWN, UiSound)
+}
+
+/// Kind of a spatial sound that limits how many sounds of the same kind play at once
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct SoundKind {
+    /// Type of the source, e.g. a character
+    pub(crate) source: TypeId,
+    pub(crate) state: AnimationState,
+}
+
+/// Message that plays a spatial sound effect at a position
+#[derive(Message)]
+pub(crate) struct PlaySpatialSound {
+    pub(crate) sound: Handle<AudioSource>,
+    pub(crate) kind: SoundKind,
+    pub(crate) pos: Vec2,
 }
 
-/// [`GlobalVolume`] doesn't apply to already-running audio entities, so this system will update them.
-fn apply_global_volume(
-    mut query: Query<(&PlaybackSettings, &mut AudioSink)>,
+/// Play [`PlaySpatialSound`] near [`SpatialListener`]
+///
+/// Sounds beyond [`SPATIAL_CULL_RADIUS`] are culled and closer sounds are preferred if more than
+/// [`MAX_CONCURRENT_SOUNDS`] of the same [`SoundKind`] would play.
+fn play_spatial_sounds(
+    listener: Single<&GlobalTransform, With<SpatialListener>>,
+    query: Query<&SoundKind>,
+    mut msgs: MessageReader<PlaySpatialSound>,
+    mut commands: Commands,
+) {
+    let listener_pos = listener.translation();
+
+    // Collect sounds within radius sorted by distance
+    let mut sounds: Vec<_> = msgs
+        .read()
+        .map(|msg| (msg.pos.distance(listener_pos.xy()), msg))
+        .filter(|(distance, _)| *distance <= SPATIAL_CULL_RADIUS)
+        .collect();
+    if sounds.is_empty() {
+        return;
+    }
+    sounds.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    // Count sounds that are already playing
+    let mut counts = HashMap::<SoundKind, usize>::new();
+    for kind in &query {
+        *counts.entry(*kind).or_default() += 1;
+    }
+
+    for (_, msg) in sounds {
+        // Continue if too many sounds of this kind are playing
+        let count = counts.entry(msg.kind).or_default();
+        if *count >= MAX_CONCURRENT_SOUNDS {
+            continue;
+        }
+        *count += 1;
+
+        // Play sound on the same plane as the listener
+        commands.spawn((
+            spatial_sound_effect(msg.sound.clone(), msg.pos.extend(listener_pos.z)),
+            msg.kind,
+        ));
+    }
+}
+
+/// Apply [`GlobalVolume`] and [`AudioBuses`] to sinks
+///
+/// [`GlobalVolume`] doesn't apply to already-running audio entities and buses are not applied by bevy
+/// at all, so this system will update new sinks and all sinks if volume is changed. [`Fade`] is also
+/// applied here.
+fn apply_volume(
+    mut query: Query<(
+        &PlaybackSettings,
+        AnyOf<(&mut AudioSink, &mut SpatialAudioSink)>,
+        Has<Music>,
+        Has<SoundEffect>,
+        Has<UiSound>,
+        Option<Ref<Fade>>,
+    )>,
     global_volume: Res<GlobalVolume>,
+    buses: Res<AudioBuses>,
 ) {
-    for (playback, mut sink) in &mut query {
-        sink.set_volume(global_volume.volume * playback.volume);
+    let changed = global_volume.is_changed() || buses.is_changed();
+
+    for (playback, (sink, spatial_sink), music, sound_effect, ui, fade) in &mut query {
+        // Continue if neither the sink is new nor volume has changed
+        let added = sink.as_ref().is_some_and(|sink| sink.is_added())
+            || spatial_sink.as_ref().is_some_and(|sink| sink.is_added());
+        let faded = fade.as_ref().is_some_and(|fade| fade.is_changed());
+        if !changed && !added && !faded {
+            continue;
+        }
+
+        let bus_volume = if music {
+            buses.get(AudioBus::Music).effective()
+        } else if sound_effect {
+            buses.get(AudioBus::SoundEffect).effective()
+        } else if ui {
+            buses.get(AudioBus::Ui).effective()
+        } else {
+            Volume::Linear(1.)
+        };
+        let fade = Volume::Linear(fade.map_or(1., |fade| fade.0));
+        let volume = global_volume.volume * bus_volume * playback.volume * fade;
+        if let Some(mut sink) = sink {
+            sink.set_volume(volume);
+        }
+        if let Some(mut sink) = spatial_sink {
+            sink.set_volume(volume);
+        }
     }
 }
@@ -0,0 +1,268 @@
+/*
+ * File: director.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Music that reacts to gameplay.
+//!
+//! [`MusicDirector`] keeps calm and intense tracks of a level playing and crossfades between them
+//! with [`MusicIntensity`]. In [`MusicMode::Playlist`] it cycles through all tracks instead.
+
+use bevy::prelude::*;
+use bevy_northstar::prelude::*;
+
+use crate::{
+    Pause,
+    audio::{Fade, music},
+    characters::{Character, damage::Health},
+    screens::Screen,
+    settings::Settings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Reset intensity for each run
+    app.init_resource::<MusicIntensity>();
+    app.add_systems(OnEnter(Screen::Gameplay), reset_music_intensity);
+
+    // Direct music and fade tracks
+    // NOTE: This is not pausable since music is ducked while paused.
+    app.add_systems(Update, (direct_music, fade_music_tracks).chain());
+}
+
+/// Duration of crossfades in seconds
+const CROSSFADE_SECS: f32 = 2.;
+/// Duration that a track plays for in [`MusicMode::Playlist`] before crossfading to the next one
+const PLAYLIST_TRACK_SECS: f32 = 90.;
+/// Factor that music is ducked by while paused
+const PAUSE_DUCK: f32 = 0.3;
+/// Distance in pixels within which chasing characters raise [`MusicIntensity`]
+const CHASE_RADIUS: f32 = 160.;
+/// Number of nearby chasing characters that result in full [`MusicIntensity`]
+const MAX_CHASERS: f32 = 4.;
+/// Rate per second at which [`MusicIntensity`] follows its target
+const INTENSITY_RATE: f32 = 0.5;
+
+/// How [`MusicDirector`] selects tracks
+#[derive(
+    serde::Serialize, serde::Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug, Reflect,
+)]
+pub(crate) enum MusicMode {
+    /// Crossfade between calm and intense tracks with [`MusicIntensity`]
+    #[default]
+    Dynamic,
+    /// Cycle through all tracks of the level
+    Playlist,
+}
+impl MusicMode {
+    /// Name that is displayed in settings
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Dynamic => "Dynamic",
+            Self::Playlist => "Playlist",
+        }
+    }
+
+    /// The other mode
+    pub(crate) fn toggled(self) -> Self {
+        match self {
+            Self::Dynamic => Self::Playlist,
+            Self::Playlist => Self::Dynamic,
+        }
+    }
+}
+
+/// Intensity of gameplay from `0.` (calm) to `1.` (intense)
+#[derive(Resource, Default, PartialEq, Debug, Reflect)]
+#[reflect(Resource)]
+pub(crate) struct MusicIntensity(pub(crate) f32);
+
+/// Directs music of a level
+///
+/// Tracks are spawned as children of this entity.
+#[derive(Component, Debug)]
+pub(crate) struct MusicDirector {
+    calm: Vec<Handle<AudioSource>>,
+    intense: Vec<Handle<AudioSource>>,
+    /// Index of the current track
+    index: usize,
+    /// Timer for the current track in [`MusicMode::Playlist`]
+    timer: Timer,
+}
+impl MusicDirector {
+    pub(crate) fn new(
+        calm: Vec<Handle<AudioSource>>,
+        intense: Vec<Handle<AudioSource>>,
+        index: usize,
+    ) -> Self {
+        Self {
+            calm,
+            intense,
+            index,
+            timer: Timer::from_seconds(PLAYLIST_TRACK_SECS, TimerMode::Repeating),
+        }
+    }
+
+    /// Tracks that should be playing with their target volume
+    fn targets(&self, mode: MusicMode, intensity: f32) -> Vec<(Handle<AudioSource>, f32)> {
+        match mode {
+            MusicMode::Dynamic => {
+                let calm = pick(&self.calm, self.index);
+                let intense = pick(&self.intense, self.index);
+                match (calm, intense) {
+                    (Some(calm), Some(intense)) => {
+                        vec![(calm, 1. - intensity), (intense, intensity)]
+                    }
+                    (Some(track), None) | (None, Some(track)) => vec![(track, 1.)],
+                    (None, None) => Vec::new(),
+                }
+            }
+            MusicMode::Playlist => {
+                let tracks: Vec<_> = self.calm.iter().chain(&self.intense).cloned().collect();
+                pick(&tracks, self.index)
+                    .map(|track| vec![(track, 1.)])
+                    .unwrap_or_default()
+            }
+        }
+    }
+}
+
+/// Pick track at index and wrap around
+fn pick(tracks: &[Handle<AudioSource>], index: usize) -> Option<Handle<AudioSource>> {
+    if tracks.is_empty() {
+        return None;
+    }
+    Some(tracks[index % tracks.len()].clone())
+}
+
+/// Music track of a [`MusicDirector`]
+#[derive(Component, Debug)]
+struct MusicTrack {
+    handle: Handle<AudioSource>,
+    /// Volume that [`Fade`] moves towards
+    target: f32,
+    /// Whether the track is still needed
+    ///
+    /// Silent layers in [`MusicMode::Dynamic`] are kept to stay in sync with other layers.
+    active: bool,
+}
+
+/// Music director bundle
+pub(crate) fn music_director(director: MusicDirector) -> impl Bundle {
+    (Name::new("Music Director"), director)
+}
+
+/// Reset [`MusicIntensity`]
+fn reset_music_intensity(mut intensity: ResMut<MusicIntensity>) {
+    intensity.0 = 0.;
+}
+
+/// Update [`MusicIntensity`] from nearby chasing characters and health of the target
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`] and is used as the chasing characters.
+/// - `A` must implement [`Character`] and is used as the target.
+pub(crate) fn update_music_intensity<T, A>(
+    target: Single<(&Transform, &Health), (With<A>, Without<T>)>,
+    chasers: Query<&Transform, (With<T>, With<Pathfind>, Without<A>)>,
+    mut intensity: ResMut<MusicIntensity>,
+    time: Res<Time>,
+) where
+    T: Character,
+    A: Character,
+{
+    let (transform, health) = target.into_inner();
+    let pos = transform.translation.xy();
+
+    // Use the higher intensity of nearby chasers and missing health
+    let chasing = chasers
+        .iter()
+        .filter(|chaser| chaser.translation.xy().distance(pos) <= CHASE_RADIUS)
+        .count() as f32;
+    let goal = (chasing / MAX_CHASERS)
+        .max(1. - health.fraction())
+        .clamp(0., 1.);
+
+    // Follow goal smoothly to avoid jumping between tracks
+    let step = INTENSITY_RATE * time.delta_secs();
+    let new = intensity.0 + (goal - intensity.0).clamp(-step, step);
+    intensity.set_if_neq(MusicIntensity(new));
+}
+
+/// Spawn and target [`MusicTrack`]s of each [`MusicDirector`]
+fn direct_music(
+    mut director_query: Query<(Entity, &mut MusicDirector, Option<&Children>)>,
+    mut track_query: Query<&mut MusicTrack>,
+    mut commands: Commands,
+    intensity: Res<MusicIntensity>,
+    pause: Res<State<Pause>>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    let mode = settings.audio.music_mode;
+    let duck = if pause.get().0 { PAUSE_DUCK } else { 1. };
+
+    for (entity, mut director, children) in &mut director_query {
+        // Advance to the next track in playlist mode
+        if mode == MusicMode::Playlist && director.timer.tick(time.delta()).just_finished() {
+            director.index = director.index.wrapping_add(1);
+        }
+
+        let mut targets = director.targets(mode, intensity.0);
+
+        // Target existing tracks and fade out tracks that are not needed anymore
+        for child in children.into_iter().flatten() {
+            let Ok(mut track) = track_query.get_mut(*child) else {
+                continue;
+            };
+            let (target, active) = targets
+                .iter()
+                .position(|(handle, _)| handle == &track.handle)
+                .map_or((0., false), |i| (targets.swap_remove(i).1 * duck, true));
+            if track.target != target || track.active != active {
+                track.target = target;
+                track.active = active;
+            }
+        }
+
+        // Spawn missing tracks silently and fade them in
+        for (handle, target) in targets {
+            commands.entity(entity).with_child((
+                Name::new("Music Track"),
+                music(handle.clone()),
+                MusicTrack {
+                    handle,
+                    target: target * duck,
+                    active: true,
+                },
+                Fade(0.),
+            ));
+        }
+    }
+}
+
+/// Move [`Fade`] of [`MusicTrack`]s towards their target and despawn silent tracks
+fn fade_music_tracks(
+    mut query: Query<(Entity, &MusicTrack, &mut Fade)>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    let step = time.delta_secs() / CROSSFADE_SECS;
+
+    for (entity, track, mut fade) in &mut query {
+        // Despawn track if it is not needed and has been faded out
+        if !track.active && fade.0 <= 0. {
+            commands.entity(entity).try_despawn();
+            continue;
+        }
+
+        let new = fade.0 + (track.target - fade.0).clamp(-step, step);
+        if fade.0 != new {
+            fade.0 = new;
+        }
+    }
+}
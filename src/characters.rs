@@ -10,38 +10,56 @@
 //! Characters
 
 pub(crate) mod animations;
+pub(crate) mod damage;
+pub(crate) mod definition;
+pub(crate) mod deformation;
+pub(crate) mod goo;
 pub(crate) mod npc;
 pub(crate) mod player;
+pub(crate) mod stamina;
+pub(crate) mod status;
 
 use std::marker::PhantomData;
 
-use bevy::{
-    color::palettes::tailwind, platform::collections::HashMap, prelude::*, reflect::Reflectable,
-};
+use bevy::{color::palettes::tailwind, prelude::*, reflect::Reflectable};
 use bevy_asset_loader::asset_collection::AssetCollection;
 use bevy_rapier2d::prelude::*;
 use bevy_spritesheet_animation::prelude::SpritesheetAnimation;
 
 use crate::{
     AppSystems,
-    characters::animations::{AnimationController, AnimationTimer, Animations},
+    characters::{
+        animations::{AnimationController, AnimationTimer, Animations, Facing},
+        damage::{ContactEffect, Health},
+        deformation::Deformation,
+        goo::{GooData, GooTrail},
+        status::StatusEffectData,
+    },
     levels::{DEFAULT_Z, SHADOW_Z, YSort},
-    logging::{error::ERR_LOADING_COLLISION_DATA, warn::WARN_INCOMPLETE_COLLISION_DATA_FALLBACK},
+    logging::error::{
+        ERR_LOADING_COLLISION_DATA, ERR_LOADING_STATS_DATA, ERR_UNVALIDATED_COLLISION_DATA,
+    },
+    particles::ParticleEffect,
 };
 
 pub(super) fn plugin(app: &mut App) {
-    // Insert `VisualMap`
-    app.insert_resource(VisualMap::default());
-
     // Add child plugins
-    app.add_plugins((animations::plugin, npc::plugin, player::plugin));
+    app.add_plugins((
+        animations::plugin,
+        damage::plugin,
+        goo::plugin,
+        npc::plugin,
+        player::plugin,
+        stamina::plugin,
+        status::plugin,
+    ));
 
     // Tick jump timer
     app.add_systems(Update, tick_jump_timer.in_set(AppSystems::TickTimers));
 }
 
-/// Jumping duration in seconds
-pub(crate) const JUMP_DURATION_SECS: f32 = 1.;
+/// Dashing duration in seconds
+pub(crate) const DASH_DURATION_SECS: f32 = 0.2;
 
 /// Applies to anything that stores character assets
 pub(crate) trait CharacterAssets
@@ -76,13 +94,15 @@ macro_rules! impl_character_assets {
 /// Applies to any character [`Component`]
 pub(crate) trait Character
 where
-    Self: Component + Default + Reflectable,
+    Self: Component + Clone + Default + Reflectable,
 {
-    fn container_bundle(
-        &self,
-        data: &(Option<String>, Option<f32>, Option<f32>),
-        pos: Vec2,
-    ) -> impl Bundle;
+    /// Assets that are loaded from the character's `*.character.ron` file
+    type Assets: CharacterAssets;
+
+    /// Particles that are spawned if the character dies
+    const DEATH_PARTICLES: Option<ParticleEffect> = None;
+
+    fn container_bundle(&self, data: &CollisionData<Self>, pos: Vec2) -> impl Bundle;
 
     fn visual_bundle(
         &self,
@@ -94,23 +114,25 @@ where
             animations.sprite.clone(),
             SpritesheetAnimation::new(animations.idle.clone()),
             AnimationController::default(),
+            Deformation::default(),
             AnimationTimer(Timer::from_seconds(animation_delay, TimerMode::Once)),
         )
     }
 
-    fn shadow_bundle(&self, shadow: &Res<Shadow<Self>>, width: f32) -> impl Bundle {
+    fn shadow_bundle(&self, shadow: &Res<Shadow<Self>>, data: &CollisionData<Self>) -> impl Bundle {
         (
+            CharacterShadow,
             YSort(SHADOW_Z),
-            Transform::from_xyz(0., -width / 2., SHADOW_Z),
+            Transform::from_translation(data.shadow_pos().extend(SHADOW_Z)),
             Mesh2d(shadow.mesh.clone()),
-            MeshMaterial2d(shadow.material.clone()),
+            MeshMaterial2d(shadow.materials[0].clone()),
         )
     }
 
     fn spawn(
         commands: &mut Commands,
-        visual_map: &mut ResMut<VisualMap>,
-        data: &(Option<String>, Option<f32>, Option<f32>),
+        data: &CollisionData<Self>,
+        stats: Option<&StatsData<Self>>,
         pos: Vec2,
         animations: &Res<Animations<Self>>,
         shadow: &Res<Shadow<Self>>,
@@ -119,42 +141,236 @@ where
         let character = Self::default();
         let container = commands.spawn(character.container_bundle(data, pos)).id();
 
+        // Add health, hit reaction, contact effect and goo trail from stats
+        if let Some(stats) = stats {
+            commands
+                .entity(container)
+                .insert((Health::new(stats.max_health), stats.hit.clone()));
+            if let Some(effect) = &stats.contact_effect {
+                commands
+                    .entity(container)
+                    .insert(ContactEffect(effect.effect()));
+            }
+            if let Some(goo) = &stats.goo {
+                commands
+                    .entity(container)
+                    .insert(GooTrail::new(goo.clone()));
+            }
+        }
+
         let visual = commands
-            .spawn(character.visual_bundle(animations, animation_delay))
+            .spawn((
+                character.visual_bundle(animations, animation_delay),
+                VisualOf(container),
+            ))
             .id();
         commands.entity(container).add_child(visual);
-        visual_map.0.insert(container, visual);
 
-        let width = data.1.unwrap_or_else(|| {
-            warn_once!("{}", WARN_INCOMPLETE_COLLISION_DATA_FALLBACK);
-            24.
-        });
-        let shadow = commands.spawn(character.shadow_bundle(shadow, width)).id();
+        let shadow = commands.spawn(character.shadow_bundle(shadow, data)).id();
         commands.entity(container).add_child(shadow);
 
+        spawn_sensors(commands, data, container);
+
         container
     }
 }
 
-/// Animation data deserialized from a ron file as a generic
+/// Collision data deserialized from a ron file as a generic
 ///
 /// ## Traits
 ///
 /// - `T` must implement [`Character`].
-#[derive(serde::Deserialize, Asset, TypePath, Default)]
+#[derive(serde::Deserialize, Asset, TypePath, Clone)]
 pub(crate) struct CollisionData<T>
 where
     T: Character,
 {
+    /// Shape of the body collider
+    pub(crate) shape: CollisionShape,
+    /// Offset of the body collider from the character's origin
+    ///
+    /// This can be used for colliders that only cover the feet of a character.
     #[serde(default)]
-    pub(crate) shape: Option<String>,
+    pub(crate) offset: Vec2,
+    /// Sensor for receiving damage
+    ///
+    /// The shape of the body collider is used if this is not set.
     #[serde(default)]
-    pub(crate) width: Option<f32>,
+    pub(crate) hurtbox: Option<SensorData>,
+    /// Sensor for dealing damage
+    ///
+    /// The shape of the body collider is used if this is not set.
     #[serde(default)]
-    pub(crate) height: Option<f32>,
+    pub(crate) hitbox: Option<SensorData>,
+    /// Shape and position of the shadow
+    #[serde(default)]
+    pub(crate) shadow: ShadowData,
     #[serde(skip)]
     _phantom: PhantomData<T>,
 }
+impl<T> CollisionData<T>
+where
+    T: Character,
+{
+    /// Width of the body collider
+    pub(crate) fn width(&self) -> f32 {
+        self.shape.size().x
+    }
+
+    /// Position of the shadow relative to the character's origin
+    pub(crate) fn shadow_pos(&self) -> Vec2 {
+        Vec2::new(0., -self.width() / 2.) + self.shadow.offset
+    }
+
+    /// Shape of the shadow
+    ///
+    /// The base radius is a quarter of the width and is scaled per axis by [`ShadowData::scale`].
+    pub(crate) fn shadow_shape(&self) -> Ellipse {
+        let half_size = (self.width() / 4.).abs() * self.shadow.scale.abs();
+        Ellipse::new(half_size.x, half_size.y)
+    }
+
+    /// Collider of the body
+    ///
+    /// This must only be called on data that has been checked with [`CollisionData::is_valid`].
+    pub(crate) fn collider(&self) -> Collider {
+        self.shape
+            .collider(self.offset)
+            .expect(ERR_UNVALIDATED_COLLISION_DATA)
+    }
+
+    /// Sensor that receives damage
+    ///
+    /// This is the hurtbox if available and the body collider otherwise.
+    fn hurt_sensor(&self) -> SensorData {
+        self.hurtbox.clone().unwrap_or_else(|| self.body_sensor())
+    }
+
+    /// Sensor that deals damage
+    ///
+    /// This is the hitbox if available and the body collider otherwise.
+    fn hit_sensor(&self) -> SensorData {
+        self.hitbox.clone().unwrap_or_else(|| self.body_sensor())
+    }
+
+    /// Sensor with the shape of the body collider
+    fn body_sensor(&self) -> SensorData {
+        SensorData {
+            shape: self.shape.clone(),
+            offset: self.offset,
+        }
+    }
+
+    /// Check if all shapes can be turned into colliders
+    pub(crate) fn is_valid(&self) -> bool {
+        self.shape.collider(self.offset).is_some()
+            && [&self.hurtbox, &self.hitbox]
+                .into_iter()
+                .flatten()
+                .all(|sensor| sensor.shape.collider(sensor.offset).is_some())
+    }
+}
+
+/// Shape of a collider
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub(crate) enum CollisionShape {
+    /// Circle with the given diameter
+    Ball { diameter: f32 },
+    /// Horizontal capsule where `width` must be greater than `height`
+    CapsuleX { width: f32, height: f32 },
+    /// Vertical capsule where `height` must be greater than `width`
+    CapsuleY { width: f32, height: f32 },
+    /// Rectangle
+    Cuboid { width: f32, height: f32 },
+    /// Convex polygon with points relative to the center
+    ConvexPolygon { points: Vec<Vec2> },
+}
+impl CollisionShape {
+    /// Size of the bounding box
+    pub(crate) fn size(&self) -> Vec2 {
+        match self {
+            Self::Ball { diameter } => Vec2::splat(*diameter),
+            Self::CapsuleX { width, height }
+            | Self::CapsuleY { width, height }
+            | Self::Cuboid { width, height } => Vec2::new(*width, *height),
+            Self::ConvexPolygon { points } => {
+                let min = points.iter().copied().reduce(Vec2::min).unwrap_or_default();
+                let max = points.iter().copied().reduce(Vec2::max).unwrap_or_default();
+                max - min
+            }
+        }
+    }
+
+    /// Collider with the given offset
+    ///
+    /// This returns [`None`] if the shape is invalid.
+    fn collider(&self, offset: Vec2) -> Option<Collider> {
+        // Return if any dimension is not positive
+        let size = self.size();
+        if size.x <= 0. || size.y <= 0. {
+            return None;
+        }
+
+        let collider = match self {
+            Self::Ball { diameter } => Collider::ball(diameter / 2.),
+            Self::CapsuleX { width, height } if width > height => {
+                Collider::capsule_x((width - height) / 2., height / 2.)
+            }
+            Self::CapsuleY { width, height } if height > width => {
+                Collider::capsule_y((height - width) / 2., width / 2.)
+            }
+            Self::CapsuleX { .. } | Self::CapsuleY { .. } => return None,
+            Self::Cuboid { width, height } => Collider::cuboid(width / 2., height / 2.),
+            Self::ConvexPolygon { points } => Collider::convex_hull(points)?,
+        };
+
+        // Wrap collider in a compound collider to apply offset
+        if offset == Vec2::ZERO {
+            Some(collider)
+        } else {
+            Some(Collider::compound(vec![(offset, 0., collider)]))
+        }
+    }
+}
+
+/// Sensor collider deserialized from a ron file
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct SensorData {
+    pub(crate) shape: CollisionShape,
+    /// Offset of the sensor from the character's origin
+    #[serde(default)]
+    pub(crate) offset: Vec2,
+}
+
+/// Shadow data deserialized from a ron file
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub(crate) struct ShadowData {
+    /// Scale of the shadow per axis
+    ///
+    /// A smaller `y` than `x` results in an elliptical shadow for top-down perspective.
+    pub(crate) scale: Vec2,
+    /// Offset of the shadow from the character's feet
+    pub(crate) offset: Vec2,
+}
+impl Default for ShadowData {
+    fn default() -> Self {
+        Self {
+            scale: Vec2::ONE,
+            offset: Vec2::ZERO,
+        }
+    }
+}
+
+/// Sensor that receives damage
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub(crate) struct Hurtbox;
+
+/// Sensor that deals damage
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub(crate) struct Hitbox;
 
 /// Handle for [`CollisionData`] as a generic
 ///
@@ -166,29 +382,160 @@ pub(crate) struct CollisionHandle<T>(pub(crate) Handle<CollisionData<T>>)
 where
     T: Character;
 
+/// Stats data deserialized from a ron file as a generic
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+#[derive(serde::Deserialize, Asset, TypePath, Clone, Default)]
+pub(crate) struct StatsData<T>
+where
+    T: Character,
+{
+    pub(crate) max_health: f32,
+    /// Maximum walking speed in pixels per second
+    pub(crate) walk_speed: f32,
+    /// Acceleration towards [`StatsData::walk_speed`] in pixels per second squared
+    pub(crate) acceleration: f32,
+    /// Deceleration without input in pixels per second squared
+    pub(crate) deceleration: f32,
+    /// Factor for acceleration and deceleration while jumping
+    pub(crate) air_control: f32,
+    pub(crate) jump: JumpData,
+    #[serde(default)]
+    pub(crate) hit: HitData,
+    /// Status effect that is applied together with contact damage
+    #[serde(default)]
+    pub(crate) contact_effect: Option<StatusEffectData>,
+    /// Goo trail that the character leaves behind
+    #[serde(default)]
+    pub(crate) goo: Option<GooData>,
+    pub(crate) max_stamina: f32,
+    pub(crate) stamina_regen_per_sec: f32,
+    pub(crate) stamina_regen_delay_secs: f32,
+    pub(crate) jump_stamina_cost: f32,
+    pub(crate) dash_stamina_cost: f32,
+    /// Speed of a dash in pixels per second
+    pub(crate) dash_speed: f32,
+    /// Cooldown between dashes in seconds
+    pub(crate) dash_cooldown_secs: f32,
+    pub(crate) sprint_stamina_cost_per_sec: f32,
+    pub(crate) sprint_speed_factor: f32,
+    #[serde(skip)]
+    _phantom: PhantomData<T>,
+}
+
+/// Jump arc deserialized from a ron file
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct JumpData {
+    /// Height of a jump if the jump action is only tapped
+    pub(crate) height: f32,
+    /// Height of a jump if the jump action is held until the highest point
+    pub(crate) max_height: f32,
+    /// Duration of the whole jump in seconds where rising and falling take half each
+    pub(crate) duration_secs: f32,
+    /// Easing of the rise which is mirrored for the fall
+    pub(crate) easing: EaseFunction,
+    /// Time in seconds that a jump is buffered if it is pressed before landing
+    #[serde(default)]
+    pub(crate) buffer_secs: f32,
+}
+impl Default for JumpData {
+    fn default() -> Self {
+        Self {
+            height: 12.,
+            max_height: 12.,
+            duration_secs: 1.,
+            easing: EaseFunction::QuadraticOut,
+            buffer_secs: 0.,
+        }
+    }
+}
+impl JumpData {
+    /// Height above the ground for the given fraction of the rise or fall
+    pub(crate) fn height_at(&self, peak: f32, fraction: f32, falling: bool) -> f32 {
+        let fraction = if falling { 1. - fraction } else { fraction };
+        peak * EasingCurve::new(0., 1., self.easing).sample_clamped(fraction)
+    }
+}
+
+/// Reaction to being hit deserialized from a ron file
+///
+/// Characters without [`StatsData`] use the default values.
+#[derive(serde::Deserialize, Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct HitData {
+    /// Duration of hit-stun in seconds
+    pub(crate) stun_secs: f32,
+    /// Speed of the knockback at the start of hit-stun in pixels per second
+    pub(crate) knockback_speed: f32,
+}
+impl Default for HitData {
+    fn default() -> Self {
+        Self {
+            stun_secs: 0.4,
+            knockback_speed: 240.,
+        }
+    }
+}
+
+/// Handle for [`StatsData`] as a generic
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+#[derive(Resource)]
+pub(crate) struct StatsHandle<T>(pub(crate) Handle<StatsData<T>>)
+where
+    T: Character;
+
 /// Current data about movement
 #[derive(Component, Default)]
+#[require(Facing)]
 pub(crate) struct Movement {
+    /// Direction of movement from input or navigation
     pub(crate) target: Vec2,
-    jump_height: f32,
+    /// Current velocity in pixels per second
+    pub(crate) velocity: Vec2,
+    /// Height above the ground while airborne
+    height: f32,
+    /// Highest point of the current jump
+    jump_peak: f32,
+    dash_direction: Vec2,
 }
 
-/// Timer that tracks jumping
+/// Timer that tracks the rise or fall of a jump
 #[derive(Component, Debug, Clone, PartialEq, Reflect)]
 #[reflect(Component)]
 struct JumpTimer(Timer);
-impl Default for JumpTimer {
-    fn default() -> Self {
+impl JumpTimer {
+    fn new(data: &JumpData) -> Self {
         Self(Timer::from_seconds(
-            JUMP_DURATION_SECS / 2.,
+            data.duration_secs / 2.,
             TimerMode::Once,
         ))
     }
 }
 
-/// Map of characters to their visual representations
-#[derive(Resource, Default)]
-pub(crate) struct VisualMap(pub(crate) HashMap<Entity, Entity>);
+/// Visual representation of a character
+///
+/// This is removed from the character automatically if the visual representation is despawned.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[relationship_target(relationship = VisualOf)]
+pub(crate) struct Visual(Entity);
+impl Visual {
+    /// Entity of the visual representation
+    pub(crate) fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Character that an entity is the visual representation of
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[relationship(relationship_target = Visual)]
+pub(crate) struct VisualOf(pub(crate) Entity);
 
 /// Shadow data for characters
 ///
@@ -201,12 +548,28 @@ where
     T: Character,
 {
     mesh: Handle<Mesh>,
-    material: Handle<ColorMaterial>,
+    /// Materials from fully opaque to most faded
+    materials: Vec<Handle<ColorMaterial>>,
     _phantom: PhantomData<T>,
 }
 
+/// Shadow of a character
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub(crate) struct CharacterShadow;
+
 /// Color for cast shadows
 const SHADOW_COLOR: Srgba = tailwind::GRAY_700;
+/// Alpha of shadows on the ground
+const SHADOW_ALPHA: f32 = 0.25;
+/// Number of fade steps for shadows
+///
+/// Steps are used so that shadows can share materials.
+const SHADOW_FADE_STEPS: usize = 4;
+/// Opacity of shadows at the highest point of a jump relative to [`SHADOW_ALPHA`]
+const SHADOW_MIN_OPACITY: f32 = 0.4;
+/// Scale of shadows at the highest point of a jump
+const SHADOW_MIN_SCALE: f32 = 0.6;
 
 /// Setup [`Shadow`]
 ///
@@ -222,38 +585,182 @@ pub(crate) fn setup_shadow<T>(
 ) where
     T: Character,
 {
-    // Get animation from `AnimationData` with `CollisionHandle`
+    // Get data from `CollisionData` with `CollisionHandle`
     let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
-    let width = data.width.unwrap_or_else(|| {
-        warn_once!("{}", WARN_INCOMPLETE_COLLISION_DATA_FALLBACK);
-        24.
-    });
+
+    // Add a material for every fade step
+    let materials = (0..SHADOW_FADE_STEPS)
+        .map(|step| {
+            let fade = step as f32 / (SHADOW_FADE_STEPS - 1) as f32;
+            let opacity = 1. - (1. - SHADOW_MIN_OPACITY) * fade;
+            materials.add(Color::from(SHADOW_COLOR.with_alpha(SHADOW_ALPHA * opacity)))
+        })
+        .collect();
 
     let resource = Shadow::<T> {
-        mesh: meshes.add(Circle::new(-width / 4.)),
-        material: materials.add(Color::from(SHADOW_COLOR.with_alpha(0.25))),
+        mesh: meshes.add(data.shadow_shape()),
+        materials,
         ..default()
     };
 
     commands.insert_resource(resource);
 }
 
-/// Collider for different shapes
-pub(crate) fn character_collider(data: &(Option<String>, Option<f32>, Option<f32>)) -> Collider {
-    let (Some(shape), Some(width), Some(height)) = data else {
-        // Return default collider if data is not complete
-        warn_once!("{}", WARN_INCOMPLETE_COLLISION_DATA_FALLBACK);
-        return Collider::ball(12.);
+/// Update the [`Shadow`] mesh and shadow positions from modified [`CollisionData`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn reload_shadow<T>(
+    mut query: Query<(&Mesh2d, &mut Transform), With<CharacterShadow>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    data: Res<Assets<CollisionData<T>>>,
+    handle: Res<CollisionHandle<T>>,
+    shadow: Res<Shadow<T>>,
+) where
+    T: Character,
+{
+    // Get data from `CollisionData` with `CollisionHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
+
+    // Replace mesh in place so that all shadows using it are updated
+    if let Some(mesh) = meshes.get_mut(&shadow.mesh) {
+        *mesh = data.shadow_shape().into();
+    }
+
+    let pos = data.shadow_pos();
+    for (mesh, mut transform) in &mut query {
+        if mesh.0 == shadow.mesh {
+            transform.translation.x = pos.x;
+            transform.translation.y = pos.y;
+        }
+    }
+}
+
+/// Shrink and fade shadows with the height of airborne characters
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn update_shadows<T>(
+    parent_query: Query<(&Movement, &Children), (With<T>, Changed<Movement>)>,
+    mut shadow_query: Query<
+        (&mut Transform, &mut MeshMaterial2d<ColorMaterial>),
+        With<CharacterShadow>,
+    >,
+    stats_data: Res<Assets<StatsData<T>>>,
+    stats_handle: Option<Res<StatsHandle<T>>>,
+    shadow: Res<Shadow<T>>,
+) where
+    T: Character,
+{
+    // Return if character has no stats since it can not jump without them
+    let Some(stats_handle) = stats_handle else {
+        return;
     };
 
-    // Set correct collider for each shape
-    // NOTE: For capsules, we just assume that the values are correct, meaning that for x: `width < height` and for y: `width > height`
-    match shape.as_str() {
-        "ball" => Collider::ball(width / 2.),
-        "capsule_x" => Collider::capsule_x((height - width) / 2., height / 2.),
-        "capsule_y" => Collider::capsule_y((width - height) / 2., width / 2.),
-        _ => Collider::cuboid(width / 2., height / 2.),
+    // Get data from `StatsData` with `StatsHandle`
+    let stats = stats_data
+        .get(stats_handle.0.id())
+        .expect(ERR_LOADING_STATS_DATA);
+
+    for (movement, children) in &parent_query {
+        // Fraction of the maximum jump height
+        let fraction = if stats.jump.max_height > 0. {
+            (movement.height / stats.jump.max_height).clamp(0., 1.)
+        } else {
+            0.
+        };
+
+        let mut iter = shadow_query.iter_many_mut(children);
+        while let Some((mut transform, mut material)) = iter.fetch_next() {
+            // Shrink shadow
+            let scale = 1. - (1. - SHADOW_MIN_SCALE) * fraction;
+            transform.scale = Vec3::new(scale, scale, 1.);
+
+            // Fade shadow
+            let step = (fraction * (SHADOW_FADE_STEPS - 1) as f32).round() as usize;
+            if material.0 != shadow.materials[step] {
+                material.0 = shadow.materials[step].clone();
+            }
+        }
+    }
+}
+
+/// Replace colliders and sensors of existing characters with modified [`CollisionData`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn reload_colliders<T>(
+    containers: Query<Entity, With<T>>,
+    sensors: Query<(Entity, &ChildOf), Or<(With<Hurtbox>, With<Hitbox>)>>,
+    mut commands: Commands,
+    data: Res<Assets<CollisionData<T>>>,
+    handle: Res<CollisionHandle<T>>,
+) where
+    T: Character,
+{
+    // Get data from `CollisionData` with `CollisionHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
+
+    // Despawn old sensors
+    for (entity, child_of) in &sensors {
+        if containers.contains(child_of.parent()) {
+            commands.entity(entity).despawn();
+        }
     }
+
+    // Replace body collider and spawn new sensors
+    for container in &containers {
+        commands.entity(container).insert(data.collider());
+        spawn_sensors(&mut commands, data, container);
+    }
+}
+
+/// Spawn hurtbox and hitbox sensors as children of the container
+fn spawn_sensors<T>(commands: &mut Commands, data: &CollisionData<T>, container: Entity)
+where
+    T: Character,
+{
+    let hurtbox = commands
+        .spawn((
+            Name::new("Hurtbox"),
+            Hurtbox,
+            sensor_bundle(&data.hurt_sensor()),
+        ))
+        .id();
+    let hitbox = commands
+        .spawn((
+            Name::new("Hitbox"),
+            Hitbox,
+            sensor_bundle(&data.hit_sensor()),
+        ))
+        .id();
+    commands.entity(container).add_children(&[hurtbox, hitbox]);
+}
+
+/// Sensor collider for a hurtbox or hitbox
+///
+/// This must only be called with sensors from data that has been checked with [`CollisionData::is_valid`].
+fn sensor_bundle(sensor: &SensorData) -> impl Bundle {
+    (
+        Transform::default(),
+        sensor
+            .shape
+            .collider(sensor.offset)
+            .expect(ERR_UNVALIDATED_COLLISION_DATA),
+        Sensor,
+        ActiveCollisionTypes::all(),
+    )
+}
+
+/// Add `translation` to the translation that the character controller applies in the next physics step
+///
+/// This is used in [`FixedUpdate`], which can run multiple times before the physics step in
+/// [`PostUpdate`], so translations are added instead of replaced.
+pub(crate) fn add_translation(controller: &mut KinematicCharacterController, translation: Vec2) {
+    controller.translation = Some(controller.translation.unwrap_or_default() + translation);
 }
 
 /// Tick jump timer
@@ -17,24 +17,31 @@
 //! - [Sprite animation](https://github.com/bevyengine/bevy/blob/latest/examples/2d/sprite_animation.rs)
 //! - [Timers](https://github.com/bevyengine/bevy/blob/latest/examples/time/timers.rs)
 
-use std::{marker::PhantomData, ops::Range};
+use std::{any::TypeId, marker::PhantomData, ops::Range};
 
-use bevy::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
 use bevy_prng::WyRand;
 use bevy_rand::{global::GlobalRng, traits::ForkableSeed as _};
 use bevy_spritesheet_animation::prelude::*;
 use rand::seq::IndexedRandom as _;
 
 use crate::{
-    AppSystems,
-    audio::sound_effect,
-    characters::{Character, CharacterAssets, JUMP_DURATION_SECS, Movement, VisualMap},
+    AppSystems, PausableSystems,
+    audio::{PlaySpatialSound, SoundKind},
+    characters::{
+        Character, CharacterAssets, DASH_DURATION_SECS, HitData, Movement, StatsData, StatsHandle,
+        Visual, deformation::DeformationData, status::StatusModifiers,
+    },
     logging::{
         error::{
             ERR_INVALID_REQUIRED_ANIMATION_DATA, ERR_LOADING_ANIMATION_DATA,
-            ERR_SPRITE_IMAGE_NOT_LOADED, ERR_UNINITIALIZED_REQUIRED_ANIMATION,
+            ERR_SPRITE_IMAGE_NOT_LOADED,
+        },
+        warn::{
+            WARN_INCOMPLETE_ANIMATION_DATA, WARN_INCOMPLETE_ASSET_DATA,
+            WARN_INVALID_ANIMATION_ATLAS, WARN_INVALID_ANIMATION_CLIP,
+            WARN_INVALID_ANIMATION_EVENT_FRAME,
         },
-        warn::{WARN_INCOMPLETE_ANIMATION_DATA, WARN_INCOMPLETE_ASSET_DATA},
     },
 };
 
@@ -45,8 +52,19 @@ pub(super) fn plugin(app: &mut App) {
     // Add plugin for sprite animation
     app.add_plugins(SpritesheetAnimationPlugin);
 
+    // Add message for animation events
+    app.add_message::<AnimationEvent>();
+
     // Tick animation timer
     app.add_systems(Update, tick_animation_timer.in_set(AppSystems::TickTimers));
+
+    // Update facing from movement
+    app.add_systems(
+        Update,
+        update_facing
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
 }
 
 /// Player animation delay
@@ -54,10 +72,13 @@ pub(crate) const ANIMATION_DELAY_RANGE: Range<f32> = 0.0..10.0;
 
 /// Animation data deserialized from a ron file as a generic.
 ///
+/// Clips are declared in `clips` by [`AnimationState`]. The `<state>_*` fields are kept for
+/// backwards compatibility and are only used for states that are not declared in `clips`.
+///
 /// ## Traits
 ///
 /// - `T` must implement [`Character`].
-#[derive(serde::Deserialize, Asset, TypePath, Default)]
+#[derive(serde::Deserialize, Asset, TypePath, Clone, Default)]
 pub(crate) struct AnimationData<T>
 where
     T: Character,
@@ -65,6 +86,8 @@ where
     atlas_columns: usize,
     atlas_rows: usize,
     #[serde(default)]
+    clips: HashMap<AnimationState, ClipData>,
+    #[serde(default)]
     idle_row: Option<usize>,
     #[serde(default)]
     idle_frames: Option<usize>,
@@ -90,9 +113,134 @@ where
     fall_frames: Option<usize>,
     #[serde(default)]
     fall_sound_frames: Option<Vec<usize>>,
+    #[serde(default)]
+    dash_row: Option<usize>,
+    #[serde(default)]
+    dash_frames: Option<usize>,
+    #[serde(default)]
+    hurt_row: Option<usize>,
+    #[serde(default)]
+    hurt_frames: Option<usize>,
+    /// Tunables for squash-and-stretch of the visual
+    #[serde(default)]
+    pub(crate) deformation: DeformationData,
     #[serde(skip)]
     _phantom: PhantomData<T>,
 }
+impl<T> AnimationData<T>
+where
+    T: Character,
+{
+    /// All clips including clips from the `<state>_*` fields
+    fn all_clips(&self) -> HashMap<AnimationState, ClipData> {
+        let legacy = [
+            (
+                AnimationState::Idle,
+                self.idle_row,
+                self.idle_frames,
+                self.idle_interval_ms,
+                None,
+            ),
+            (
+                AnimationState::Walk,
+                self.walk_row,
+                self.walk_frames,
+                self.walk_interval_ms,
+                self.walk_sound_frames.as_ref(),
+            ),
+            (
+                AnimationState::Jump,
+                self.jump_row,
+                self.jump_frames,
+                None,
+                self.jump_sound_frames.as_ref(),
+            ),
+            (
+                AnimationState::Fall,
+                self.fall_row,
+                self.fall_frames,
+                None,
+                self.fall_sound_frames.as_ref(),
+            ),
+            (
+                AnimationState::Dash,
+                self.dash_row,
+                self.dash_frames,
+                None,
+                None,
+            ),
+            (
+                AnimationState::Hurt,
+                self.hurt_row,
+                self.hurt_frames,
+                None,
+                None,
+            ),
+        ];
+
+        let mut clips = self.clips.clone();
+        for (state, row, frames, interval_ms, sound_frames) in legacy {
+            let (Some(row), Some(frames)) = (row, frames) else {
+                continue;
+            };
+            clips.entry(state).or_insert_with(|| ClipData {
+                row,
+                frames,
+                interval_ms,
+                repeat: None,
+                sound_frames: sound_frames.cloned().unwrap_or_default(),
+                events: HashMap::new(),
+                directions: HashMap::new(),
+            });
+        }
+        clips
+    }
+}
+
+/// Data for a single animation clip
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+struct ClipData {
+    row: usize,
+    frames: usize,
+    /// Interval between frames
+    ///
+    /// This is derived from the state's duration if not set.
+    #[serde(default)]
+    interval_ms: Option<u32>,
+    /// Repeat mode
+    ///
+    /// This is derived from the state if not set.
+    #[serde(default)]
+    repeat: Option<ClipRepeat>,
+    /// Frames that sounds are played on
+    ///
+    /// This is a shorthand for [`AnimationEventKind::Sound`] in `events`.
+    #[serde(default)]
+    sound_frames: Vec<usize>,
+    /// Events by frame that are written as [`AnimationEvent`]
+    #[serde(default)]
+    events: HashMap<usize, Vec<AnimationEventKind>>,
+    /// Rows for specific facing directions
+    ///
+    /// This is used for 4- or 8-directional animations. Missing directions fall back to `row`.
+    #[serde(default)]
+    directions: HashMap<Facing, usize>,
+}
+
+/// Repeat mode for an animation clip
+#[derive(serde::Deserialize, Clone, Copy, Debug)]
+enum ClipRepeat {
+    Loop,
+    Times(usize),
+}
+impl From<ClipRepeat> for AnimationRepeat {
+    fn from(repeat: ClipRepeat) -> Self {
+        match repeat {
+            ClipRepeat::Loop => AnimationRepeat::Loop,
+            ClipRepeat::Times(times) => AnimationRepeat::Times(times),
+        }
+    }
+}
 
 /// Handle for [`AnimationData`] as a generic
 ///
@@ -118,20 +266,216 @@ where
 {
     pub(crate) sprite: Sprite,
     pub(crate) idle: Handle<Animation>,
-    pub(crate) walk: Option<Handle<Animation>>,
-    pub(crate) jump: Option<Handle<Animation>>,
-    pub(crate) fall: Option<Handle<Animation>>,
+    clips: HashMap<AnimationState, ClipHandles>,
+    events: HashMap<AnimationState, HashMap<usize, Vec<AnimationEventKind>>>,
     _phantom: PhantomData<T>,
 }
+impl<T> Animations<T>
+where
+    T: Character,
+{
+    /// Animations of the given state or its fallback
+    ///
+    /// Returns [`None`] if neither the state nor any of its fallbacks have an animation.
+    fn get(&self, state: AnimationState) -> Option<&ClipHandles> {
+        self.clips
+            .get(&state)
+            .or_else(|| state.fallback().and_then(|fallback| self.get(fallback)))
+    }
+
+    /// Events of the given state for the given frame
+    fn events(&self, state: AnimationState, frame: usize) -> &[AnimationEventKind] {
+        self.events
+            .get(&state)
+            .and_then(|events| events.get(&frame))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Animations of a single clip
+struct ClipHandles {
+    /// Animation that is used if there is no animation for the facing direction
+    default: Handle<Animation>,
+    directions: HashMap<Facing, Handle<Animation>>,
+}
+impl ClipHandles {
+    /// Animation for the facing direction and whether the sprite has to be flipped
+    ///
+    /// Flipping is [`None`] if the sprite should keep its current flipping.
+    fn get(&self, facing: Facing) -> (&Handle<Animation>, Option<bool>) {
+        // Use directional animation or its closest direction
+        if let Some(handle) = facing
+            .closest()
+            .into_iter()
+            .find_map(|direction| self.directions.get(&direction))
+        {
+            return (handle, Some(false));
+        }
+
+        // Use mirrored directional animation
+        if let Some(handle) = facing
+            .mirrored()
+            .closest()
+            .into_iter()
+            .find_map(|direction| self.directions.get(&direction))
+        {
+            return (handle, Some(true));
+        }
+
+        // Use default animation and flip if facing left
+        let flip = match facing.direction().x {
+            0 => None,
+            x => Some(x < 0),
+        };
+        (&self.default, flip)
+    }
+
+    /// Whether the animation belongs to this clip
+    fn contains(&self, handle: &Handle<Animation>) -> bool {
+        &self.default == handle
+            || self
+                .directions
+                .values()
+                .any(|direction| direction == handle)
+    }
+}
+
+/// Direction that a character is facing
+///
+/// This is derived from [`Movement`] and kept while idle.
+#[derive(
+    Component, serde::Deserialize, Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect,
+)]
+#[reflect(Component)]
+pub(crate) enum Facing {
+    Right,
+    UpRight,
+    Up,
+    UpLeft,
+    Left,
+    DownLeft,
+    #[default]
+    Down,
+    DownRight,
+}
+impl Facing {
+    /// All directions in counterclockwise order starting at [`Facing::Right`]
+    const ALL: [Self; 8] = [
+        Self::Right,
+        Self::UpRight,
+        Self::Up,
+        Self::UpLeft,
+        Self::Left,
+        Self::DownLeft,
+        Self::Down,
+        Self::DownRight,
+    ];
+
+    /// Facing that is closest to the given direction
+    fn from_direction(direction: Vec2) -> Self {
+        let octant = (direction.to_angle() / std::f32::consts::FRAC_PI_4).round() as i32;
+        Self::ALL[octant.rem_euclid(8) as usize]
+    }
+
+    /// Direction as a unit grid vector
+    fn direction(self) -> IVec2 {
+        match self {
+            Self::Right => IVec2::new(1, 0),
+            Self::UpRight => IVec2::new(1, 1),
+            Self::Up => IVec2::new(0, 1),
+            Self::UpLeft => IVec2::new(-1, 1),
+            Self::Left => IVec2::new(-1, 0),
+            Self::DownLeft => IVec2::new(-1, -1),
+            Self::Down => IVec2::new(0, -1),
+            Self::DownRight => IVec2::new(1, -1),
+        }
+    }
+
+    /// Facing that is mirrored horizontally
+    fn mirrored(self) -> Self {
+        let direction = self.direction() * IVec2::new(-1, 1);
+        Self::from_direction(direction.as_vec2())
+    }
+
+    /// This facing followed by the closest directions for 4-directional animations
+    ///
+    /// NOTE: Diagonals prefer the horizontal direction.
+    fn closest(self) -> Vec<Self> {
+        let direction = self.direction();
+        if direction.x == 0 || direction.y == 0 {
+            return vec![self];
+        }
+        vec![
+            self,
+            Self::from_direction(Vec2::new(direction.x as f32, 0.)),
+            Self::from_direction(Vec2::new(0., direction.y as f32)),
+        ]
+    }
+}
 
 /// Current state of animation
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
+#[derive(serde::Deserialize, Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub(crate) enum AnimationState {
     #[default]
     Idle,
     Walk,
     Jump,
     Fall,
+    Dash,
+    Hurt,
+}
+impl AnimationState {
+    /// State whose animation is used if there is no animation for this state
+    fn fallback(self) -> Option<Self> {
+        match self {
+            Self::Dash => Some(Self::Walk),
+            Self::Hurt => Some(Self::Idle),
+            Self::Idle | Self::Walk | Self::Jump | Self::Fall => None,
+        }
+    }
+
+    /// Repeat mode that is used if a clip does not declare one
+    fn default_repeat(self) -> AnimationRepeat {
+        match self {
+            Self::Idle | Self::Walk => AnimationRepeat::Loop,
+            Self::Jump | Self::Fall | Self::Dash | Self::Hurt => AnimationRepeat::Times(1),
+        }
+    }
+
+    /// Duration of the state in seconds that is used to derive the interval if a clip does not declare one
+    ///
+    /// Rising and falling each take half of the jump duration from [`StatsData`].
+    fn duration_secs(self, jump_duration_secs: Option<f32>, hit_stun_secs: f32) -> Option<f32> {
+        match self {
+            Self::Jump | Self::Fall => jump_duration_secs.map(|duration| duration / 2.),
+            Self::Dash => Some(DASH_DURATION_SECS),
+            Self::Hurt => Some(hit_stun_secs),
+            Self::Idle | Self::Walk => None,
+        }
+    }
+}
+
+/// Kind of an [`AnimationEvent`]
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum AnimationEventKind {
+    /// Play a sound for the current state
+    Sound,
+    Footstep,
+    SpawnDust,
+    /// Enable the hitbox of the character
+    HitboxOn,
+    /// Disable the hitbox of the character
+    HitboxOff,
+    Land,
+}
+
+/// Message that is written if an animation reaches a frame with an event
+#[derive(Message, Debug, Clone, Copy)]
+pub(crate) struct AnimationEvent {
+    /// Character whose animation reached the frame
+    pub(crate) entity: Entity,
+    pub(crate) state: AnimationState,
+    pub(crate) kind: AnimationEventKind,
 }
 
 /// Controller for animations
@@ -139,14 +483,14 @@ pub(crate) enum AnimationState {
 pub(crate) struct AnimationController {
     /// Used to determine next animation
     pub(crate) state: AnimationState,
-    /// Used to determine if we should play sound again
-    pub(crate) sound_frame: usize,
+    /// Used to determine if we should write events again
+    pub(crate) event_frame: usize,
 }
 impl Default for AnimationController {
     fn default() -> Self {
         Self {
             state: AnimationState::default(),
-            sound_frame: usize::MAX,
+            event_frame: usize::MAX,
         }
     }
 }
@@ -177,6 +521,8 @@ pub(crate) fn setup_animations<T, A>(
     mut global_animations: ResMut<Assets<Animation>>,
     data: Res<Assets<AnimationData<T>>>,
     handle: Res<AnimationHandle<T>>,
+    stats_data: Res<Assets<StatsData<T>>>,
+    stats_handle: Option<Res<StatsHandle<T>>>,
     assets: Res<A>,
     images: Res<Assets<Image>>,
 ) where
@@ -186,6 +532,24 @@ pub(crate) fn setup_animations<T, A>(
     // Get animation from `AnimationData` with `AnimationHandle`
     let data = data.get(handle.0.id()).expect(ERR_LOADING_ANIMATION_DATA);
 
+    // Get jump and hit-stun duration from `StatsData` with `StatsHandle` if the character has stats
+    let stats = stats_handle.and_then(|handle| stats_data.get(handle.0.id()));
+    let jump_duration_secs = stats.map(|stats| stats.jump.duration_secs);
+    let hit_stun_secs =
+        stats.map_or_else(|| HitData::default().stun_secs, |stats| stats.hit.stun_secs);
+
+    // Warn if the atlas does not evenly divide the image
+    let image = images
+        .get(assets.get_image())
+        .expect(ERR_SPRITE_IMAGE_NOT_LOADED);
+    if data.atlas_columns == 0
+        || data.atlas_rows == 0
+        || !image.width().is_multiple_of(data.atlas_columns as u32)
+        || !image.height().is_multiple_of(data.atlas_rows as u32)
+    {
+        warn_once!("{}", WARN_INVALID_ANIMATION_ATLAS);
+    }
+
     // Set sprite sheet and generate sprite from it
     let sprite_sheet = Spritesheet::new(assets.get_image(), data.atlas_columns, data.atlas_rows);
     let sprite = sprite_sheet
@@ -193,103 +557,179 @@ pub(crate) fn setup_animations<T, A>(
         .expect(ERR_SPRITE_IMAGE_NOT_LOADED)
         .sprite(&mut atlas_layouts);
 
-    // Idle animation: This is the only required animation
-    let idle = animation_handle(
-        &mut global_animations,
-        &sprite_sheet,
-        data.idle_row,
-        data.idle_frames,
-        data.idle_interval_ms,
-        AnimationRepeat::Loop,
-    )
-    .expect(ERR_INVALID_REQUIRED_ANIMATION_DATA);
-
-    // Walk animation
-    let walk = animation_handle(
-        &mut global_animations,
-        &sprite_sheet,
-        data.walk_row,
-        data.walk_frames,
-        data.walk_interval_ms,
-        AnimationRepeat::Loop,
-    );
+    // Create animations for all valid clips
+    let mut clips = HashMap::new();
+    let mut events = HashMap::new();
+    for (state, clip) in data.all_clips() {
+        // Continue if clip does not fit into the atlas
+        if clip.frames < 1 || clip.row >= data.atlas_rows || clip.frames > data.atlas_columns {
+            warn!("{} {:?}", WARN_INVALID_ANIMATION_CLIP, state);
+            continue;
+        }
 
-    // Jump animation
-    let jump = data
-        .jump_frames
-        .map(|frames| {
-            let interval_ms =
-                (JUMP_DURATION_SECS * 500. / frames.max(1) as f32).min(u32::MAX as f32) as u32;
-            animation_handle(
-                &mut global_animations,
-                &sprite_sheet,
-                data.jump_row,
-                data.jump_frames,
-                Some(interval_ms),
-                AnimationRepeat::Times(1),
-            )
-        })
-        .unwrap_or_else(|| None);
-
-    // Fall animation
-    let fall = data
-        .fall_frames
-        .map(|frames| {
-            let interval_ms =
-                (JUMP_DURATION_SECS * 500. / frames.max(1) as f32).min(u32::MAX as f32) as u32;
-            animation_handle(
-                &mut global_animations,
-                &sprite_sheet,
-                data.fall_row,
-                data.fall_frames,
-                Some(interval_ms),
-                AnimationRepeat::Times(1),
+        // Derive interval from duration of the state if not set
+        let Some(interval_ms) = clip.interval_ms.or_else(|| {
+            state
+                .duration_secs(jump_duration_secs, hit_stun_secs)
+                .map(|duration| (duration * 1000. / clip.frames as f32).min(u32::MAX as f32) as u32)
+        }) else {
+            warn!("{} {:?}", WARN_INVALID_ANIMATION_CLIP, state);
+            continue;
+        };
+
+        // Create animation for a single row
+        let repeat = clip
+            .repeat
+            .map_or_else(|| state.default_repeat(), Into::into);
+        let mut create_animation = |row: usize| {
+            global_animations.add(
+                sprite_sheet
+                    .create_animation()
+                    .add_horizontal_strip(0, row, clip.frames)
+                    .set_clip_duration(AnimationDuration::PerFrame(interval_ms))
+                    .set_repetitions(repeat)
+                    .build(),
             )
-        })
-        .unwrap_or_else(|| None);
+        };
+
+        // Create default and directional animations
+        let default = create_animation(clip.row);
+        let mut directions = HashMap::new();
+        for (facing, row) in &clip.directions {
+            // Continue if row does not fit into the atlas
+            if *row >= data.atlas_rows {
+                warn!("{} {:?} {:?}", WARN_INVALID_ANIMATION_CLIP, state, facing);
+                continue;
+            }
+            directions.insert(*facing, create_animation(*row));
+        }
+
+        clips.insert(
+            state,
+            ClipHandles {
+                default,
+                directions,
+            },
+        );
+        let mut clip_events = clip.events;
+        for frame in clip.sound_frames {
+            clip_events
+                .entry(frame)
+                .or_default()
+                .push(AnimationEventKind::Sound);
+        }
+
+        // Skip events on frames that are not part of the clip
+        // NOTE: Frames start at 0, so the last frame is `clip.frames - 1`.
+        clip_events.retain(|frame, _| {
+            if *frame < clip.frames {
+                return true;
+            }
+            warn!(
+                "{} {:?} {}",
+                WARN_INVALID_ANIMATION_EVENT_FRAME, state, frame
+            );
+            false
+        });
+        events.insert(state, clip_events);
+    }
+
+    // Idle animation: This is the only required animation
+    let idle = clips
+        .get(&AnimationState::Idle)
+        .expect(ERR_INVALID_REQUIRED_ANIMATION_DATA)
+        .default
+        .clone();
 
     // Add to `Animations`
     commands.insert_resource(Animations::<T> {
         sprite,
         idle,
-        walk,
-        jump,
-        fall,
+        clips,
+        events,
         ..default()
     });
 }
 
-/// Animation handle customized via parameters
+/// Create a UI image with an idle animation from [`AnimationData`]
 ///
-/// Returns [`Some`] for valid parameters
-/// Returns [`None`] for invalid `row`, `frames` or `interval` parameter
-fn animation_handle(
-    global_animations: &mut ResMut<Assets<Animation>>,
-    sprite_sheet: &Spritesheet,
-    row: Option<usize>,
-    frames: Option<usize>,
-    interval: Option<u32>,
-    repetitions: AnimationRepeat,
-) -> Option<Handle<Animation>> {
-    let (Some(row), Some(frames), Some(interval)) = (row, frames, interval) else {
-        warn_once!(WARN_INCOMPLETE_ANIMATION_DATA);
-        return None;
-    };
+/// This is used for previews outside of gameplay. Returns [`None`] if the image is not loaded or
+/// the idle clip does not fit into the atlas.
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn idle_preview<T>(
+    data: &AnimationData<T>,
+    image: &Handle<Image>,
+    images: &Assets<Image>,
+    atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    global_animations: &mut Assets<Animation>,
+) -> Option<(ImageNode, SpritesheetAnimation)>
+where
+    T: Character,
+{
+    let clip = data.all_clips().remove(&AnimationState::Idle)?;
+    let interval_ms = clip.interval_ms?;
 
-    if frames < 1 {
+    // Return if clip does not fit into the atlas
+    if clip.frames < 1 || clip.row >= data.atlas_rows || clip.frames > data.atlas_columns {
         return None;
     }
 
-    Some(
-        global_animations.add(
-            sprite_sheet
-                .create_animation()
-                .add_horizontal_strip(0, row, frames)
-                .set_clip_duration(AnimationDuration::PerFrame(interval))
-                .set_repetitions(repetitions)
-                .build(),
-        ),
-    )
+    // Set sprite sheet and generate image node from it
+    let sprite_sheet = Spritesheet::new(image, data.atlas_columns, data.atlas_rows);
+    let image_node = sprite_sheet
+        .with_loaded_image(images)?
+        .image_node(atlas_layouts);
+
+    let animation = global_animations.add(
+        sprite_sheet
+            .create_animation()
+            .add_horizontal_strip(0, clip.row, clip.frames)
+            .set_clip_duration(AnimationDuration::PerFrame(interval_ms))
+            .set_repetitions(AnimationRepeat::Loop)
+            .build(),
+    );
+
+    Some((image_node, SpritesheetAnimation::new(animation)))
+}
+
+/// Update sprites of existing characters after [`Animations`] have been rebuilt
+///
+/// Animations are switched by [`update_animations`] since their handles have changed.
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn reload_sprites<T>(
+    parent_query: Query<&Visual, With<T>>,
+    mut child_query: Query<&mut Sprite, Without<T>>,
+    animations: Res<Animations<T>>,
+) where
+    T: Character,
+{
+    for visual in &parent_query {
+        // Extract `sprite` from `child_query`
+        let Ok(mut sprite) = child_query.get_mut(visual.entity()) else {
+            continue;
+        };
+
+        sprite.image = animations.sprite.image.clone();
+        sprite.texture_atlas = animations.sprite.texture_atlas.clone();
+    }
+}
+
+/// Update [`Facing`] from [`Movement`] and keep it while not moving
+pub(crate) fn update_facing(mut query: Query<(&Movement, &mut Facing), Changed<Movement>>) {
+    for (movement, mut facing) in &mut query {
+        // Continue if we are not moving
+        if movement.target == Vec2::ZERO {
+            continue;
+        }
+
+        facing.set_if_neq(Facing::from_direction(movement.target));
+    }
 }
 
 /// Tick animation timer
@@ -305,7 +745,7 @@ pub(crate) fn tick_animation_timer(mut query: Query<&mut AnimationTimer>, time:
 ///
 /// - `T` must implement [`Character`].
 pub(crate) fn update_animations<T>(
-    parent_query: Query<(Entity, &mut Movement), With<T>>,
+    parent_query: Query<(&Visual, &Facing, Option<&StatusModifiers>), With<T>>,
     mut child_query: Query<
         (
             &mut AnimationController,
@@ -316,16 +756,13 @@ pub(crate) fn update_animations<T>(
         Without<T>,
     >,
     animations: Res<Animations<T>>,
-    visual_map: Res<VisualMap>,
 ) where
     T: Character,
 {
-    for (entity, movement) in &parent_query {
+    for (visual, facing, modifiers) in &parent_query {
         // Extract `animation_controller` from `child_query`
-        let Some(visual) = visual_map.0.get(&entity) else {
-            continue;
-        };
-        let Ok((mut controller, mut sprite, mut animation, timer)) = child_query.get_mut(*visual)
+        let Ok((mut controller, mut sprite, mut animation, timer)) =
+            child_query.get_mut(visual.entity())
         else {
             continue;
         };
@@ -335,164 +772,136 @@ pub(crate) fn update_animations<T>(
             animation.reset();
         }
 
+        // Scale animation speed with status modifiers and freeze animation while stunned
+        if let Some(modifiers) = modifiers {
+            animation.speed_factor = modifiers.speed_factor;
+            animation.playing = !modifiers.stunned;
+        }
+
         // Set translation to target translation because we even want to animate if walking against a wall
         let state = controller.state;
 
+        // Get animation of current `AnimationState`
+        let Some(clip) = animations.get(state) else {
+            warn_once!("{}", WARN_INCOMPLETE_ANIMATION_DATA);
+            continue;
+        };
+        let (handle, flip) = clip.get(*facing);
+
         // Sprite flipping
-        let dx = movement.target.x;
-        if dx != 0. {
-            sprite.flip_x = dx < 0.;
+        if let Some(flip) = flip {
+            sprite.flip_x = flip;
         }
 
-        // Match to current `AnimationState`
-        match state {
-            AnimationState::Walk
-                if &animation.animation
-                    != animations
-                        .walk
-                        .as_ref()
-                        .expect(ERR_UNINITIALIZED_REQUIRED_ANIMATION) =>
-            {
-                animation.switch(
-                    animations
-                        .walk
-                        .as_ref()
-                        .expect(ERR_UNINITIALIZED_REQUIRED_ANIMATION)
-                        .clone(),
-                );
-            }
-            AnimationState::Idle if animation.animation != animations.idle => {
-                animation.switch(animations.idle.clone());
-            }
-            AnimationState::Jump
-                if &animation.animation
-                    != animations
-                        .jump
-                        .as_ref()
-                        .expect(ERR_UNINITIALIZED_REQUIRED_ANIMATION) =>
-            {
-                animation.switch(
-                    animations
-                        .jump
-                        .as_ref()
-                        .expect(ERR_UNINITIALIZED_REQUIRED_ANIMATION)
-                        .clone(),
-                );
-            }
-            AnimationState::Fall
-                if &animation.animation
-                    != animations
-                        .fall
-                        .as_ref()
-                        .expect(ERR_UNINITIALIZED_REQUIRED_ANIMATION) =>
-            {
-                animation.switch(
-                    animations
-                        .fall
-                        .as_ref()
-                        .expect(ERR_UNINITIALIZED_REQUIRED_ANIMATION)
-                        .clone(),
-                );
-            }
-            _ => continue,
+        // Continue if animation is already playing
+        if &animation.animation == handle {
+            continue;
+        }
+
+        // Keep frame counter if only the direction has changed
+        if clip.contains(&animation.animation) {
+            animation.animation = handle.clone();
+            continue;
         }
+        animation.switch(handle.clone());
 
-        // Reset sound frame
-        controller.sound_frame = usize::MAX;
+        // Reset event frame
+        controller.event_frame = usize::MAX;
     }
 }
 
-/// Update animation sounds
+/// Write [`AnimationEvent`] if the animation reaches a frame with events
 ///
 /// ## Traits
 ///
 /// - `T` must implement [`Character`].
-/// - `A` must implement [`CharacterAssets`]
-pub(crate) fn update_animation_sounds<T, A>(
-    mut rng: Single<&mut WyRand, With<AnimationRng>>,
-    parent_query: Query<Entity, With<T>>,
-    mut child_query: Query<(&mut AnimationController, &mut SpritesheetAnimation), Without<T>>,
-    mut commands: Commands,
-    data: Res<Assets<AnimationData<T>>>,
-    handle: Res<AnimationHandle<T>>,
-    visual_map: Res<VisualMap>,
-    assets: Res<A>,
+pub(crate) fn write_animation_events<T>(
+    parent_query: Query<(Entity, &Visual), With<T>>,
+    mut child_query: Query<(&mut AnimationController, &SpritesheetAnimation), Without<T>>,
+    mut msgs: MessageWriter<AnimationEvent>,
+    animations: Res<Animations<T>>,
 ) where
     T: Character,
-    A: CharacterAssets,
 {
-    // Get animation from `AnimationData` with `AnimationHandle`
-    let data = data.get(handle.0.id()).expect(ERR_LOADING_ANIMATION_DATA);
-
-    for entity in &parent_query {
+    for (entity, visual) in &parent_query {
         // Extract `animation_controller` from `child_query`
-        let Some(visual) = visual_map.0.get(&entity) else {
+        let Ok((mut controller, animation)) = child_query.get_mut(visual.entity()) else {
             continue;
         };
-        let Ok((mut controller, animation)) = child_query.get_mut(*visual) else {
+
+        // Continue if events have already been written for this frame
+        let frame = animation.progress.frame;
+        if controller.event_frame == frame {
             continue;
-        };
+        }
+        controller.event_frame = frame;
 
-        // Set translation to target translation because we even want to animate if walking against a wall
         let state = controller.state;
+        for kind in animations.events(state, frame) {
+            msgs.write(AnimationEvent {
+                entity,
+                state,
+                kind: *kind,
+            });
+        }
+    }
+}
 
-        // Continue if sound has already been played
-        if controller.sound_frame == animation.progress.frame {
+/// Play spatial sounds at the character on [`AnimationEventKind::Sound`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+/// - `A` must implement [`CharacterAssets`]
+pub(crate) fn play_animation_sounds<T, A>(
+    mut rng: Single<&mut WyRand, With<AnimationRng>>,
+    query: Query<&GlobalTransform, With<T>>,
+    mut msgs: MessageReader<AnimationEvent>,
+    mut sound_msgs: MessageWriter<PlaySpatialSound>,
+    assets: Res<A>,
+) where
+    T: Character,
+    A: CharacterAssets,
+{
+    for msg in msgs.read() {
+        // Continue if this is not a sound event for this character
+        if msg.kind != AnimationEventKind::Sound {
             continue;
         }
+        let Ok(transform) = query.get(msg.entity) else {
+            continue;
+        };
 
-        // Match to current `AnimationState`
-        let Some(sound) = (match state {
-            AnimationState::Walk => choose_sound(
-                rng.as_mut(),
-                &animation.progress.frame,
-                &data.walk_sound_frames,
-                assets.get_walk_sounds(),
-            ),
-            AnimationState::Jump => choose_sound(
-                rng.as_mut(),
-                &animation.progress.frame,
-                &data.jump_sound_frames,
-                assets.get_jump_sounds(),
-            ),
-            AnimationState::Fall => choose_sound(
-                rng.as_mut(),
-                &animation.progress.frame,
-                &data.fall_sound_frames,
-                assets.get_fall_sounds(),
-            ),
-            _ => None,
-        }) else {
-            // Reset sound frame
-            controller.sound_frame = usize::MAX;
+        // Match to `AnimationState` of the event
+        let sounds = match msg.state {
+            AnimationState::Walk => assets.get_walk_sounds(),
+            AnimationState::Jump => assets.get_jump_sounds(),
+            AnimationState::Fall => assets.get_fall_sounds(),
+            _ => &None,
+        };
+        let Some(sound) = choose_sound(rng.as_mut(), sounds) else {
             continue;
         };
 
         // Play sound
-        commands.spawn(sound_effect(sound));
-        controller.sound_frame = animation.progress.frame;
+        sound_msgs.write(PlaySpatialSound {
+            sound,
+            kind: SoundKind {
+                source: TypeId::of::<T>(),
+                state: msg.state,
+            },
+            pos: transform.translation().xy(),
+        });
     }
 }
 
-/// Choose a random customized via parameters for current frame.
+/// Choose a random sound
 ///
-/// Returns [`Some`] if current frame is a fall sound frame.
-/// Returns [`None`] if current frame is not a fall sound frame or on missing data.
+/// Returns [`None`] on missing data.
 fn choose_sound(
     rng: &mut WyRand,
-    current_frame: &usize,
-    frames: &Option<Vec<usize>>,
     sounds: &Option<Vec<Handle<AudioSource>>>,
 ) -> Option<Handle<AudioSource>> {
-    // Return `None` if frame data is missing or does not contain current frame
-    let Some(frames) = frames else {
-        warn_once!("{}", WARN_INCOMPLETE_ANIMATION_DATA);
-        return None;
-    };
-    if !frames.contains(current_frame) {
-        return None;
-    }
-
     // Return none if asset data is missing
     let Some(sounds) = sounds else {
         warn_once!("{}", WARN_INCOMPLETE_ASSET_DATA);
@@ -0,0 +1,367 @@
+/*
+ * File: damage.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Damage between characters with knockback, hit-stun and sprite flashing as feedback.
+
+use bevy::{color::palettes::tailwind, prelude::*};
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    AppSystems, PausableSystems,
+    characters::{
+        Character, HitData, Hitbox, Hurtbox, Movement, Visual, add_translation,
+        animations::{AnimationController, AnimationEvent, AnimationEventKind, AnimationState},
+        status::{ApplyStatusEffect, StatusEffect, StatusModifiers},
+    },
+    particles::{ParticleEffect, SpawnParticles},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Add message for damage
+    app.add_message::<Damage>();
+
+    // Tick hit-stun timer
+    app.add_systems(
+        Update,
+        tick_hit_stun_timer
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+
+    // Enable and disable hitboxes from animation events
+    app.add_systems(
+        Update,
+        toggle_hitboxes
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+
+    // Apply knockback at a fixed timestep
+    app.add_systems(FixedUpdate, apply_knockback.in_set(PausableSystems));
+
+    // Apply damage and feedback and stop hit-stun depending on timer
+    app.add_systems(
+        Update,
+        (apply_damage, apply_hit_flash, limit_hit_stun)
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+}
+
+/// Damage that is dealt on contact
+const CONTACT_DAMAGE: f32 = 10.;
+
+/// Duration of the sprite flash in seconds
+const HIT_FLASH_DURATION_SECS: f32 = 0.12;
+
+/// Color for the sprite flash: rgb(248, 113, 113)
+const HIT_FLASH_COLOR: Srgba = tailwind::RED_400;
+
+/// Message that is written if a character has been hit
+#[derive(Message, Debug, Clone)]
+pub(crate) struct Damage {
+    /// Character that has been hit
+    pub(crate) target: Entity,
+    /// Position that the hit originated from
+    pub(crate) source: Vec2,
+    /// Health that is removed from the character
+    pub(crate) amount: f32,
+    /// Status effect that is applied to the character if the hit lands
+    pub(crate) effect: Option<StatusEffect>,
+}
+
+/// Health of a character
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct Health {
+    pub(crate) current: f32,
+    pub(crate) max: f32,
+}
+impl Health {
+    pub(crate) fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// Current health as a fraction of maximum health.
+    pub(crate) fn fraction(&self) -> f32 {
+        if self.max <= 0. {
+            return 0.;
+        }
+        (self.current / self.max).clamp(0., 1.)
+    }
+}
+
+/// Marker for characters that damage other characters on contact
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+pub(crate) struct ContactDamage;
+
+/// Status effect that is applied together with [`ContactDamage`]
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct ContactEffect(pub(crate) StatusEffect);
+
+/// Timer that tracks hit-stun
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct HitStun(pub(crate) Timer);
+
+/// Knockback velocity at the start of hit-stun
+#[derive(Component, Default, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+struct Knockback(Vec2);
+
+/// Write [`Damage`] for characters whose [`Hurtbox`] intersects the [`Hitbox`] of characters that
+/// have [`ContactDamage`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`] and is used as the character that deals damage.
+/// - `A` must implement [`Character`] and is used as the character that receives damage.
+pub(crate) fn damage_on_contact<T, A>(
+    hitboxes: Query<(Entity, &ChildOf), With<Hitbox>>,
+    hurtboxes: Query<&ChildOf, With<Hurtbox>>,
+    origins: Query<
+        (&Transform, Option<&ContactEffect>),
+        (With<T>, With<ContactDamage>, Without<A>),
+    >,
+    targets: Query<Entity, (With<A>, Without<T>)>,
+    rapier_context: ReadRapierContext,
+    mut msgs: MessageWriter<Damage>,
+) where
+    T: Character,
+    A: Character,
+{
+    let Ok(rapier_context) = rapier_context.single() else {
+        return;
+    };
+
+    for (hitbox, child_of) in &hitboxes {
+        // Continue if hitbox does not belong to a character that deals damage
+        let Ok((origin, effect)) = origins.get(child_of.parent()) else {
+            continue;
+        };
+        let source = origin.translation.xy();
+
+        for (collider1, collider2, intersecting) in rapier_context.intersection_pairs_with(hitbox) {
+            // Continue if shapes do not overlap
+            if !intersecting {
+                continue;
+            }
+
+            // Continue if other collider is not the hurtbox of a character that receives damage
+            let other = if collider1 == hitbox {
+                collider2
+            } else {
+                collider1
+            };
+            let Some(target) = hurtboxes
+                .get(other)
+                .ok()
+                .map(ChildOf::parent)
+                .filter(|target| targets.contains(*target))
+            else {
+                continue;
+            };
+
+            msgs.write(Damage {
+                target,
+                source,
+                amount: CONTACT_DAMAGE,
+                effect: effect.map(|effect| effect.0.clone()),
+            });
+        }
+    }
+}
+
+/// Enable or disable [`Hitbox`]es on [`AnimationEventKind::HitboxOn`] and [`AnimationEventKind::HitboxOff`]
+///
+/// Hitboxes are enabled by default, so characters without these events always deal damage.
+fn toggle_hitboxes(
+    mut msgs: MessageReader<AnimationEvent>,
+    parent_query: Query<&Children>,
+    hitboxes: Query<Entity, With<Hitbox>>,
+    mut commands: Commands,
+) {
+    for msg in msgs.read() {
+        // Continue if this is not a hitbox event
+        let enabled = match msg.kind {
+            AnimationEventKind::HitboxOn => true,
+            AnimationEventKind::HitboxOff => false,
+            _ => continue,
+        };
+        let Ok(children) = parent_query.get(msg.entity) else {
+            continue;
+        };
+
+        for hitbox in hitboxes.iter_many(children) {
+            if enabled {
+                commands.entity(hitbox).remove::<ColliderDisabled>();
+            } else {
+                commands.entity(hitbox).insert(ColliderDisabled);
+            }
+        }
+    }
+}
+
+/// Apply [`Damage`] by reducing [`Health`], applying its status effect and starting hit-stun and
+/// knockback from [`HitData`]
+fn apply_damage(
+    mut msgs: MessageReader<Damage>,
+    mut parent_query: Query<
+        (&Visual, &Transform, &mut Health, &StatusModifiers, &HitData),
+        Without<HitStun>,
+    >,
+    mut child_query: Query<&mut AnimationController>,
+    mut commands: Commands,
+    mut particle_msgs: MessageWriter<SpawnParticles>,
+    mut status_msgs: MessageWriter<ApplyStatusEffect>,
+) {
+    for msg in msgs.read() {
+        // Continue if target can not be hit
+        let Ok((visual, transform, mut health, modifiers, hit)) = parent_query.get_mut(msg.target)
+        else {
+            continue;
+        };
+        if modifiers.invulnerable {
+            continue;
+        }
+
+        // Extract `animation_controller` from `child_query`
+        let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
+            continue;
+        };
+
+        // Continue if we are jumping
+        // NOTE: This means that jumping can be used to dodge.
+        let state = animation_controller.state;
+        if state == AnimationState::Jump || state == AnimationState::Fall {
+            continue;
+        }
+
+        // Reduce health, spawn sparks and apply status effect
+        health.current -= msg.amount;
+        particle_msgs.write(SpawnParticles {
+            effect: ParticleEffect::HitSparks,
+            pos: transform.translation.xy(),
+        });
+        if let Some(effect) = &msg.effect {
+            status_msgs.write(ApplyStatusEffect {
+                target: msg.target,
+                effect: effect.clone(),
+            });
+        }
+
+        // Push target away from source
+        let direction = (transform.translation.xy() - msg.source).normalize_or(Vec2::X);
+        commands.entity(msg.target).insert((
+            HitStun(Timer::from_seconds(hit.stun_secs, TimerMode::Once)),
+            Knockback(direction * hit.knockback_speed),
+        ));
+        animation_controller.state = AnimationState::Hurt;
+    }
+}
+
+/// Apply knockback that slows down over the duration of hit-stun
+///
+/// This runs in [`FixedUpdate`] so that movement does not depend on the frame rate.
+fn apply_knockback(
+    mut query: Query<(&mut KinematicCharacterController, &Knockback, &HitStun)>,
+    time: Res<Time<Fixed>>,
+) {
+    for (mut character_controller, knockback, timer) in &mut query {
+        let factor = 1. - timer.0.fraction();
+        add_translation(
+            &mut character_controller,
+            knockback.0 * factor * time.delta_secs(),
+        );
+    }
+}
+
+/// Tint the visual representation of characters at the start of hit-stun
+fn apply_hit_flash(parent_query: Query<(&Visual, &HitStun)>, mut child_query: Query<&mut Sprite>) {
+    for (visual, timer) in &parent_query {
+        // Extract `sprite` from `child_query`
+        let Ok(mut sprite) = child_query.get_mut(visual.entity()) else {
+            continue;
+        };
+
+        sprite.color = if timer.0.elapsed_secs() < HIT_FLASH_DURATION_SECS {
+            HIT_FLASH_COLOR.into()
+        } else {
+            Color::WHITE
+        };
+    }
+}
+
+/// Limit hit-stun by removing it after specific time and then switching to walk or idle
+fn limit_hit_stun(
+    parent_query: Query<(Entity, &Visual, &Movement, &HitStun)>,
+    mut child_query: Query<(&mut AnimationController, &mut Sprite)>,
+    mut commands: Commands,
+) {
+    for (entity, visual, movement, timer) in &parent_query {
+        // Continue if timer has not finished
+        if !timer.0.just_finished() {
+            continue;
+        }
+
+        // Extract `animation_controller` and `sprite` from `child_query`
+        let Ok((mut animation_controller, mut sprite)) = child_query.get_mut(visual.entity())
+        else {
+            continue;
+        };
+
+        // Remove hit-stun and reset tint
+        commands.entity(entity).remove::<(HitStun, Knockback)>();
+        sprite.color = Color::WHITE;
+
+        // Set animation state depending on whether we are still walking
+        animation_controller.state = if movement.target == Vec2::ZERO {
+            AnimationState::Idle
+        } else {
+            AnimationState::Walk
+        };
+    }
+}
+
+/// Despawn characters without health and spawn their [`Character::DEATH_PARTICLES`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn despawn_dead<T>(
+    query: Query<(Entity, &Transform, &Health), With<T>>,
+    mut commands: Commands,
+    mut msgs: MessageWriter<SpawnParticles>,
+) where
+    T: Character,
+{
+    for (entity, transform, health) in &query {
+        if health.current > 0. {
+            continue;
+        }
+
+        if let Some(effect) = T::DEATH_PARTICLES {
+            msgs.write(SpawnParticles {
+                effect,
+                pos: transform.translation.xy(),
+            });
+        }
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Tick hit-stun timer
+fn tick_hit_stun_timer(mut query: Query<&mut HitStun>, time: Res<Time>) {
+    for mut timer in &mut query {
+        timer.0.tick(time.delta());
+    }
+}
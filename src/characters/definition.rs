@@ -0,0 +1,387 @@
+/*
+ * File: definition.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ * -----
+ * Heavily inspired by:
+ * - https://github.com/NiklasEi/bevy_asset_loader
+ * - https://github.com/NiklasEi/bevy_common_assets/tree/main
+ */
+
+//! Character definitions that combine all data of a character in a single ron file.
+
+use std::marker::PhantomData;
+
+use bevy::{asset::LoadState, platform::collections::HashMap, prelude::*};
+use bevy_asset_loader::prelude::*;
+use bevy_common_assets::ron::RonAssetPlugin;
+use iyes_progress::prelude::*;
+
+use crate::{
+    AppSystems, PausableSystems, asset_modified,
+    characters::{
+        Character, CollisionData, CollisionHandle, StatsData, StatsHandle,
+        animations::{self, AnimationData, AnimationHandle, Animations},
+        deformation, reload_colliders, reload_shadow, setup_shadow, update_shadows,
+    },
+    logging::{
+        error::{
+            ERR_FAILED_CHARACTER_DATA, ERR_INVALID_COLLISION_DATA, ERR_LOADING_CHARACTER_DATA,
+        },
+        warn::WARN_INVALID_COLLISION_DATA_RELOAD,
+    },
+    particles,
+    screens::Screen,
+};
+
+/// Plugin that wires up loading, setup, animations and sounds for a [`Character`]
+///
+/// Characters with a fixed definition are loaded in [`Screen::Loading`]. For selectable
+/// characters, [`CharacterHandle`] has to be inserted and the assets of the chosen
+/// [`CharacterData`] have to be registered in [`DynamicAssets`] before entering
+/// [`Screen::CharacterLoading`].
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) struct CharacterPlugin<T>
+where
+    T: Character,
+{
+    /// Path to the `*.character.ron` file if the definition is fixed
+    path: Option<&'static str>,
+    _phantom: PhantomData<T>,
+}
+impl<T> CharacterPlugin<T>
+where
+    T: Character,
+{
+    /// Character with a fixed definition
+    pub(crate) fn new(path: &'static str) -> Self {
+        Self {
+            path: Some(path),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Character with a definition that is chosen at runtime
+    pub(crate) fn selectable() -> Self {
+        Self {
+            path: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<T> Plugin for CharacterPlugin<T>
+where
+    T: Character,
+{
+    fn build(&self, app: &mut App) {
+        // Add ron asset plugin and assets that are extracted from it
+        app.add_plugins(RonAssetPlugin::<CharacterData<T>>::new(&["character.ron"]));
+        app.init_asset::<CollisionData<T>>();
+        app.init_asset::<AnimationData<T>>();
+        app.init_asset::<StatsData<T>>();
+
+        // Insert Animation resource
+        app.insert_resource(Animations::<T>::default());
+
+        // Validate fixed definitions while loading
+        // NOTE: Selectable definitions are validated with all player definitions in `screens::loading`.
+        if self.path.is_some() {
+            app.add_systems(
+                Update,
+                validate_character_data::<T>
+                    .track_progress::<Screen>()
+                    .run_if(in_state(Screen::Loading)),
+            );
+        }
+
+        // Extract data and setup shadow and animations
+        app.add_systems(
+            OnEnter(Screen::Gameplay),
+            (
+                setup_character_data::<T>,
+                (
+                    setup_shadow::<T>,
+                    animations::setup_animations::<T, T::Assets>,
+                ),
+            )
+                .chain(),
+        );
+
+        // Animation updates and events
+        app.add_systems(
+            Update,
+            (
+                animations::update_animations::<T>
+                    .after(animations::tick_animation_timer)
+                    .after(animations::update_facing),
+                animations::write_animation_events::<T>,
+                particles::spawn_animation_particles::<T>.run_if(in_state(Screen::Gameplay)),
+                animations::play_animation_sounds::<T, T::Assets>
+                    .run_if(in_state(Screen::Gameplay)),
+            )
+                .chain()
+                .in_set(AppSystems::Update)
+                .in_set(PausableSystems),
+        );
+
+        // Squash-and-stretch of visuals
+        app.add_systems(
+            Update,
+            deformation::update_deformation::<T>
+                .after(animations::update_animations::<T>)
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update)
+                .in_set(PausableSystems),
+        );
+
+        // Shadow updates for airborne characters
+        app.add_systems(
+            Update,
+            update_shadows::<T>
+                .run_if(in_state(Screen::Gameplay))
+                .in_set(AppSystems::Update)
+                .in_set(PausableSystems),
+        );
+
+        // Hot-reload character data and rebuild everything that depends on it
+        app.add_systems(
+            Update,
+            (
+                reload_character_data::<T>,
+                (
+                    animations::setup_animations::<T, T::Assets>,
+                    animations::reload_sprites::<T>,
+                )
+                    .chain()
+                    .run_if(asset_modified::<AnimationData<T>>),
+                (reload_shadow::<T>, reload_colliders::<T>)
+                    .run_if(asset_modified::<CollisionData<T>>),
+            )
+                .run_if(in_state(Screen::Gameplay)),
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // NOTE: Loading states have to be configured in `finish` since they are added in `screens::loading`.
+        let Some(path) = self.path else {
+            // Load assets of the chosen definition in `LoadingState<Screen::CharacterLoading>`
+            app.configure_loading_state(
+                LoadingStateConfig::new(Screen::CharacterLoading).load_collection::<T::Assets>(),
+            );
+            return;
+        };
+
+        // Load `CharacterData` and keep the handle
+        let handle = app.world().resource::<AssetServer>().load(path);
+        app.insert_resource(CharacterHandle::<T>(handle));
+
+        // Register assets from `CharacterData` in `LoadingState<Screen::Loading>`
+        app.configure_loading_state(
+            LoadingStateConfig::new(Screen::Loading)
+                .register_dynamic_asset_collection::<CharacterData<T>>()
+                .with_dynamic_assets_file::<CharacterData<T>>(path)
+                .load_collection::<T::Assets>(),
+        );
+    }
+}
+
+/// Character data deserialized from a `*.character.ron` file as a generic
+///
+/// This combines collision, animation, stats and assets of a character.
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+#[derive(serde::Deserialize, Asset, TypePath)]
+#[serde(bound = "")]
+pub(crate) struct CharacterData<T>
+where
+    T: Character,
+{
+    /// Name that is displayed for the character
+    #[serde(default)]
+    pub(crate) name: String,
+    collision: CollisionData<T>,
+    pub(crate) animation: AnimationData<T>,
+    #[serde(default)]
+    pub(crate) stats: Option<StatsData<T>>,
+    /// Dynamic assets that are used for [`Character::Assets`]
+    assets: HashMap<String, StandardDynamicAsset>,
+}
+impl<T> CharacterData<T>
+where
+    T: Character,
+{
+    /// Check if the collision data can be turned into colliders
+    pub(crate) fn is_valid(&self) -> bool {
+        self.collision.is_valid()
+    }
+
+    /// Path of the sprite sheet image
+    ///
+    /// This is used for previews where [`Character::Assets`] are not loaded.
+    pub(crate) fn image_path(&self) -> Option<&str> {
+        self.assets.iter().find_map(|(key, asset)| match asset {
+            StandardDynamicAsset::Image { path, .. } if key.ends_with(".image") => {
+                Some(path.as_str())
+            }
+            _ => None,
+        })
+    }
+}
+impl<T> DynamicAssetCollection for CharacterData<T>
+where
+    T: Character,
+{
+    fn register(&self, dynamic_assets: &mut DynamicAssets) {
+        for (key, asset) in self.assets.iter() {
+            dynamic_assets.register_asset(key, Box::new(asset.clone()));
+        }
+    }
+}
+
+/// Handle for [`CharacterData`] as a generic
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+#[derive(Resource)]
+pub(crate) struct CharacterHandle<T>(pub(crate) Handle<CharacterData<T>>)
+where
+    T: Character;
+
+/// Track loading and validation of [`CharacterData`]
+///
+/// Loading does not finish if the file could not be read or parsed or if the collision data is
+/// invalid. This is reported with the path of the file.
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+fn validate_character_data<T>(
+    data: Res<Assets<CharacterData<T>>>,
+    handle: Res<CharacterHandle<T>>,
+    assets: Res<AssetServer>,
+    mut reported: Local<bool>,
+) -> Progress
+where
+    T: Character,
+{
+    let path = || handle.0.path().map(ToString::to_string).unwrap_or_default();
+
+    // Return if `CharacterData` has not been loaded and report failed loading only once
+    let Some(data) = data.get(handle.0.id()) else {
+        if let LoadState::Failed(err) = assets.load_state(handle.0.id())
+            && !*reported
+        {
+            error!("{} {} ({})", ERR_FAILED_CHARACTER_DATA, path(), err);
+            *reported = true;
+        }
+        return false.into();
+    };
+    if data.is_valid() {
+        return true.into();
+    }
+
+    // Report invalid collision data only once
+    if !*reported {
+        error!("{} {}", ERR_INVALID_COLLISION_DATA, path());
+        *reported = true;
+    }
+    false.into()
+}
+
+/// Extract [`CollisionData`], [`AnimationData`] and [`StatsData`] from [`CharacterData`]
+///
+/// The data has been validated while loading.
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+fn setup_character_data<T>(
+    mut commands: Commands,
+    data: Res<Assets<CharacterData<T>>>,
+    handle: Res<CharacterHandle<T>>,
+    mut collisions: ResMut<Assets<CollisionData<T>>>,
+    mut animations: ResMut<Assets<AnimationData<T>>>,
+    mut stats: ResMut<Assets<StatsData<T>>>,
+) where
+    T: Character,
+{
+    // Get data from `CharacterData` with `CharacterHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_CHARACTER_DATA);
+
+    let handle = CollisionHandle::<T>(collisions.add(data.collision.clone()));
+    commands.insert_resource(handle);
+
+    let handle = AnimationHandle::<T>(animations.add(data.animation.clone()));
+    commands.insert_resource(handle);
+
+    // Stats are optional since not every character needs them
+    if let Some(data) = &data.stats {
+        let handle = StatsHandle::<T>(stats.add(data.clone()));
+        commands.insert_resource(handle);
+    }
+}
+
+/// Update [`CollisionData`], [`AnimationData`] and [`StatsData`] if [`CharacterData`] has been modified
+///
+/// Invalid collision data is skipped with a warning so that the previous colliders are kept.
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+fn reload_character_data<T>(
+    mut msgs: MessageReader<AssetEvent<CharacterData<T>>>,
+    data: Res<Assets<CharacterData<T>>>,
+    handle: Res<CharacterHandle<T>>,
+    mut collisions: ResMut<Assets<CollisionData<T>>>,
+    collision_handle: Res<CollisionHandle<T>>,
+    mut animations: ResMut<Assets<AnimationData<T>>>,
+    animation_handle: Res<AnimationHandle<T>>,
+    mut stats: ResMut<Assets<StatsData<T>>>,
+    stats_handle: Option<Res<StatsHandle<T>>>,
+) where
+    T: Character,
+{
+    // Return if `CharacterData` has not been modified
+    let id = handle.0.id();
+    let mut modified = false;
+    for msg in msgs.read() {
+        modified |= msg.is_modified(id);
+    }
+    if !modified {
+        return;
+    }
+
+    // Get data from `CharacterData` with `CharacterHandle`
+    let data = data.get(id).expect(ERR_LOADING_CHARACTER_DATA);
+
+    // Replace collision data if it is valid
+    if data.collision.is_valid() {
+        if let Some(collision) = collisions.get_mut(collision_handle.0.id()) {
+            *collision = data.collision.clone();
+        }
+    } else {
+        let path = handle.0.path().map(ToString::to_string).unwrap_or_default();
+        warn!("{} {}", WARN_INVALID_COLLISION_DATA_RELOAD, path);
+    }
+
+    // Replace animation data
+    if let Some(animation) = animations.get_mut(animation_handle.0.id()) {
+        *animation = data.animation.clone();
+    }
+
+    // Replace stats data
+    if let (Some(data), Some(handle)) = (&data.stats, stats_handle)
+        && let Some(stats) = stats.get_mut(handle.0.id())
+    {
+        *stats = data.clone();
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * File: deformation.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Procedural squash-and-stretch of character visuals.
+//!
+//! This only scales the visual representation so that colliders are unaffected.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{
+    characters::{
+        Character, Visual,
+        animations::{AnimationController, AnimationData, AnimationHandle, AnimationState},
+    },
+    logging::error::ERR_LOADING_ANIMATION_DATA,
+    settings::Settings,
+};
+
+/// Maximum time step of the spring simulation in seconds
+///
+/// Larger steps are clamped to keep the spring stable on frame drops.
+const MAX_STEP_SECS: f32 = 1. / 30.;
+
+/// Lowest deformation where `-1` would flatten the visual completely
+const MIN_DEFORMATION: f32 = -0.6;
+
+/// Squash-and-stretch tunables deserialized from a ron file
+///
+/// Impulses are added to the velocity of the spring. Positive deformation stretches the visual
+/// vertically and negative deformation squashes it while keeping its area.
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub(crate) struct DeformationData {
+    /// Force that pulls the visual back to its rest shape
+    pub(crate) stiffness: f32,
+    /// Damping of the spring
+    pub(crate) damping: f32,
+    /// Stretch impulse at the start of a jump
+    pub(crate) jump_stretch: f32,
+    /// Squash impulse on landing
+    pub(crate) land_squash: f32,
+    /// Squash impulse on getting hit
+    pub(crate) hurt_squash: f32,
+    /// Amplitude of the wobble while idle
+    pub(crate) idle_wobble: f32,
+    /// Frequency of the wobble while idle in Hz
+    pub(crate) idle_wobble_hz: f32,
+}
+impl Default for DeformationData {
+    fn default() -> Self {
+        Self {
+            stiffness: 300.,
+            damping: 14.,
+            jump_stretch: 2.,
+            land_squash: 2.5,
+            hurt_squash: 3.,
+            idle_wobble: 0.,
+            idle_wobble_hz: 1.5,
+        }
+    }
+}
+
+/// Spring state of the deformation of a visual
+#[derive(Component, Default, Debug, Clone, PartialEq)]
+pub(crate) struct Deformation {
+    /// Current deformation where `0` is the rest shape
+    amount: f32,
+    velocity: f32,
+    /// State at the last update that is used to detect transitions
+    state: AnimationState,
+}
+impl Deformation {
+    /// Scale of the visual that keeps its area
+    fn scale(&self) -> Vec3 {
+        let y = 1. + self.amount;
+        Vec3::new(1. / y, y, 1.)
+    }
+}
+
+/// Add impulses on [`AnimationState`] transitions and simulate the spring of [`Deformation`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn update_deformation<T>(
+    parent_query: Query<&Visual, With<T>>,
+    mut child_query: Query<
+        (
+            Entity,
+            &AnimationController,
+            &mut Deformation,
+            &mut Transform,
+        ),
+        Without<T>,
+    >,
+    data: Res<Assets<AnimationData<T>>>,
+    handle: Res<AnimationHandle<T>>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) where
+    T: Character,
+{
+    // Get data from `AnimationData` with `AnimationHandle`
+    let data = &data
+        .get(handle.0.id())
+        .expect(ERR_LOADING_ANIMATION_DATA)
+        .deformation;
+    let delta = time.delta_secs().min(MAX_STEP_SECS);
+
+    for visual in &parent_query {
+        // Extract components from `child_query`
+        let Ok((entity, animation_controller, mut deformation, mut transform)) =
+            child_query.get_mut(visual.entity())
+        else {
+            continue;
+        };
+
+        // Add impulse on state transitions
+        let state = animation_controller.state;
+        if state != deformation.state {
+            deformation.velocity += match (deformation.state, state) {
+                (_, AnimationState::Jump) => data.jump_stretch,
+                (AnimationState::Fall, AnimationState::Idle | AnimationState::Walk) => {
+                    -data.land_squash
+                }
+                (_, AnimationState::Hurt) => -data.hurt_squash,
+                _ => 0.,
+            };
+            deformation.state = state;
+        }
+
+        // Keep rest shape if motion is reduced
+        if settings.accessibility.reduce_motion {
+            deformation.amount = 0.;
+            deformation.velocity = 0.;
+            transform.scale = Vec3::ONE;
+            continue;
+        }
+
+        // Wobble around the rest shape while idle
+        // NOTE: The phase is derived from the entity so that characters do not wobble in sync.
+        let rest = if state == AnimationState::Idle {
+            let phase = (entity.index() as f32 * 0.618).fract() * TAU;
+            data.idle_wobble * (time.elapsed_secs() * data.idle_wobble_hz * TAU + phase).sin()
+        } else {
+            0.
+        };
+
+        // Simulate spring
+        let acceleration =
+            data.stiffness * (rest - deformation.amount) - data.damping * deformation.velocity;
+        deformation.velocity += acceleration * delta;
+        deformation.amount =
+            (deformation.amount + deformation.velocity * delta).max(MIN_DEFORMATION);
+
+        transform.scale = deformation.scale();
+    }
+}
@@ -0,0 +1,273 @@
+/*
+ * File: goo.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Goo trails that are left behind by characters and slow down other characters.
+
+use bevy::{platform::collections::HashSet, prelude::*};
+
+use crate::{
+    AppSystems, PausableSystems,
+    characters::{
+        Character, Visual,
+        animations::{AnimationController, AnimationState},
+        status::{ApplyStatusEffect, StatusEffectData},
+    },
+    levels::{DECAL_Z, YSort},
+    logging::error::ERR_LOADING_TILE_DATA,
+    procgen::{CHUNK_SIZE, ProcGenController, ProcGenerated, TileData, TileHandle},
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Spawn goo pool
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_goo_pool);
+
+    // Tick goo timers
+    app.add_systems(
+        Update,
+        tick_goo_timers
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+
+    // Fade out goo
+    app.add_systems(
+        Update,
+        fade_goo
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+}
+
+/// Number of goo decals in the pool
+const GOO_POOL_SIZE: usize = 64;
+
+/// Color for goo: rgba(163, 230, 53, 153)
+const GOO_COLOR: Srgba = Srgba::new(0.639, 0.902, 0.208, 0.6);
+
+/// Goo trail data deserialized from a ron file
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Reflect)]
+pub(crate) struct GooData {
+    /// Duration of a goo decal in seconds
+    pub(crate) duration_secs: f32,
+    /// Duration of the fade at the end of a goo decal in seconds
+    pub(crate) fade_secs: f32,
+    /// Status effect that is applied to characters walking on goo
+    pub(crate) effect: StatusEffectData,
+}
+
+/// Goo decal that is stored in a pool
+///
+/// Inactive decals are hidden and can be reused.
+#[derive(Component, Default, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct Goo {
+    active: bool,
+    /// Chunk that contains the decal
+    chunk_pos: IVec2,
+    timer: Timer,
+    /// Data of the trail that has left the decal
+    data: Option<GooData>,
+}
+impl Goo {
+    /// Priority for reuse where inactive decals are preferred over the oldest active decal
+    fn reuse_priority(&self) -> f32 {
+        if self.active {
+            self.timer.elapsed_secs()
+        } else {
+            f32::INFINITY
+        }
+    }
+}
+
+/// Goo trail of a character with the tile that it has last crossed
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct GooTrail {
+    tile_pos: Option<IVec2>,
+    data: GooData,
+}
+impl GooTrail {
+    pub(crate) fn new(data: GooData) -> Self {
+        Self {
+            tile_pos: None,
+            data,
+        }
+    }
+}
+
+/// Spawn hidden goo decals for the pool
+fn spawn_goo_pool(mut commands: Commands) {
+    for _ in 0..GOO_POOL_SIZE {
+        commands.spawn((
+            Name::new("Goo"),
+            Goo::default(),
+            Sprite::from_color(GOO_COLOR, Vec2::ONE),
+            Transform::from_translation(Vec3::Z * DECAL_Z),
+            YSort(DECAL_Z),
+            Visibility::Hidden,
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+/// Leave goo on every tile that a character with [`GooTrail`] crosses
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`] and is used as the character that leaves goo.
+/// - `A` must implement [`ProcGenerated`] and is used as a level's procedurally generated item.
+pub(crate) fn drop_goo<T, A>(
+    mut characters: Query<(&Transform, &mut GooTrail), With<T>>,
+    mut goo_query: Query<(&mut Goo, &mut Transform, &mut Sprite, &mut Visibility), Without<T>>,
+    data: Res<Assets<TileData<A>>>,
+    handle: Res<TileHandle<A>>,
+) where
+    T: Character,
+    A: ProcGenerated,
+{
+    // Get data from `TileData` with `TileHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
+    let tile_size = Vec2::new(data.tile_height, data.tile_width);
+
+    for (transform, mut trail) in &mut characters {
+        // Continue if we are still on the same tile
+        let tile_pos = (transform.translation.xy() / tile_size).floor().as_ivec2();
+        if trail.tile_pos == Some(tile_pos) {
+            continue;
+        }
+        trail.tile_pos = Some(tile_pos);
+
+        // Reuse inactive or oldest goo decal
+        let Some((mut goo, mut goo_transform, mut sprite, mut visibility)) = goo_query
+            .iter_mut()
+            .max_by(|(a, ..), (b, ..)| a.reuse_priority().total_cmp(&b.reuse_priority()))
+        else {
+            return;
+        };
+        goo.active = true;
+        goo.chunk_pos = (tile_pos.as_vec2() / CHUNK_SIZE.as_vec2())
+            .floor()
+            .as_ivec2();
+        goo.timer = Timer::from_seconds(trail.data.duration_secs, TimerMode::Once);
+        goo.data = Some(trail.data.clone());
+        goo_transform.translation = ((tile_pos.as_vec2() + 0.5) * tile_size).extend(DECAL_Z);
+        sprite.custom_size = Some(tile_size);
+        sprite.color = GOO_COLOR.into();
+        *visibility = Visibility::Inherited;
+    }
+}
+
+/// Apply the status effect of goo to characters that are walking on it
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`] and is used as the character that is affected.
+/// - `A` must implement [`ProcGenerated`] and is used as a level's procedurally generated item.
+pub(crate) fn slow_on_goo<T, A>(
+    characters: Query<(Entity, &Visual, &Transform), With<T>>,
+    goo_query: Query<(&Goo, &Transform), Without<T>>,
+    child_query: Query<&AnimationController>,
+    mut msgs: MessageWriter<ApplyStatusEffect>,
+    data: Res<Assets<TileData<A>>>,
+    handle: Res<TileHandle<A>>,
+) where
+    T: Character,
+    A: ProcGenerated,
+{
+    // Get data from `TileData` with `TileHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
+    let half_tile_size = Vec2::new(data.tile_height, data.tile_width) / 2.;
+
+    for (entity, visual, transform) in &characters {
+        // Extract `animation_controller` from `child_query`
+        let Ok(animation_controller) = child_query.get(visual.entity()) else {
+            continue;
+        };
+
+        // Continue if we are jumping
+        // NOTE: This means that jumping can be used to avoid goo.
+        let state = animation_controller.state;
+        if state == AnimationState::Jump || state == AnimationState::Fall {
+            continue;
+        }
+
+        // Continue if we are not on goo
+        let pos = transform.translation.xy();
+        let Some(data) = goo_query.iter().find_map(|(goo, goo_transform)| {
+            let on_goo = (goo_transform.translation.xy() - pos)
+                .abs()
+                .cmplt(half_tile_size)
+                .all();
+            goo.data.as_ref().filter(|_| goo.active && on_goo)
+        }) else {
+            continue;
+        };
+
+        msgs.write(ApplyStatusEffect {
+            target: entity,
+            effect: data.effect.effect(),
+        });
+    }
+}
+
+/// Return goo to the pool if its chunk is no longer stored in [`ProcGenController<T>`]
+///
+/// ## Traits
+///
+/// - `T` must implement [`ProcGenerated`] and is used as a level's procedurally generated item.
+pub(crate) fn release_despawned_goo<T>(
+    mut goo_query: Query<(&mut Goo, &mut Visibility)>,
+    controller: Res<ProcGenController<T>>,
+) where
+    T: ProcGenerated,
+{
+    let chunks: HashSet<IVec2> = controller.positions.values().copied().collect();
+
+    for (mut goo, mut visibility) in &mut goo_query {
+        if goo.active && !chunks.contains(&goo.chunk_pos) {
+            goo.active = false;
+            *visibility = Visibility::Hidden;
+        }
+    }
+}
+
+/// Fade out goo at the end of its duration and return it to the pool after
+fn fade_goo(mut query: Query<(&mut Goo, &mut Sprite, &mut Visibility)>) {
+    for (mut goo, mut sprite, mut visibility) in &mut query {
+        // Continue if goo is in the pool
+        if !goo.active {
+            continue;
+        }
+
+        if goo.timer.is_finished() {
+            goo.active = false;
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        let fade_secs = goo.data.as_ref().map_or(0., |data| data.fade_secs);
+        let fade = if fade_secs > 0. {
+            (goo.timer.remaining_secs() / fade_secs).min(1.)
+        } else {
+            1.
+        };
+        sprite.color = GOO_COLOR.with_alpha(GOO_COLOR.alpha * fade).into();
+    }
+}
+
+/// Tick goo timers
+fn tick_goo_timers(mut query: Query<&mut Goo>, time: Res<Time>) {
+    for mut goo in &mut query {
+        if goo.active {
+            goo.timer.tick(time.delta());
+        }
+    }
+}
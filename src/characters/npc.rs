@@ -22,38 +22,29 @@ use bevy_rapier2d::prelude::*;
 use crate::{
     AppSystems, PausableSystems,
     characters::{
-        Character, CharacterAssets, JumpTimer, Movement,
-        animations::{self, Animations},
-        character_collider, setup_shadow,
+        Character, CharacterAssets, CollisionData, Movement,
+        damage::{ContactDamage, despawn_dead},
+        definition::CharacterPlugin,
+        status::StatusEffects,
     },
     impl_character_assets,
     levels::{DEFAULT_Z, YSort},
+    particles::ParticleEffect,
     procgen::ProcGenerated,
     screens::Screen,
 };
 
 pub(super) fn plugin(app: &mut App) {
-    // Insert Animation resource
-    app.insert_resource(Animations::<Slime>::default());
+    // Add character plugin
+    app.add_plugins(CharacterPlugin::<Slime>::new(
+        "data/characters/npc/slime.character.ron",
+    ));
 
-    // Setup slime shadow
-    app.add_systems(OnEnter(Screen::Gameplay), setup_shadow::<Slime>);
-
-    // Animation setup
-    app.add_systems(
-        OnEnter(Screen::Gameplay),
-        animations::setup_animations::<Slime, SlimeAssets>,
-    );
-
-    // Animation updates
+    // Despawn dead slimes
     app.add_systems(
         Update,
-        (
-            animations::update_animations::<Slime>.after(animations::tick_animation_timer),
-            animations::update_animation_sounds::<Slime, SlimeAssets>
-                .run_if(in_state(Screen::Gameplay)),
-        )
-            .chain()
+        despawn_dead::<Slime>
+            .run_if(in_state(Screen::Gameplay))
             .in_set(AppSystems::Update)
             .in_set(PausableSystems),
     );
@@ -81,21 +72,21 @@ impl_character_assets!(SlimeAssets);
 pub(crate) struct Npc;
 
 /// Slime marker
-#[derive(Component, Default, Reflect)]
+#[derive(Component, Clone, Default, Reflect)]
 pub(crate) struct Slime;
 impl Character for Slime {
-    fn container_bundle(
-        &self,
-        data: &(Option<String>, Option<f32>, Option<f32>),
-        pos: Vec2,
-    ) -> impl Bundle {
+    type Assets = SlimeAssets;
+
+    const DEATH_PARTICLES: Option<ParticleEffect> = Some(ParticleEffect::GooSplash);
+
+    fn container_bundle(&self, data: &CollisionData<Self>, pos: Vec2) -> impl Bundle {
         (
             Name::new("Slime"),
             Npc,
             Self,
             Transform::from_translation(pos.extend(DEFAULT_Z)),
             YSort(DEFAULT_Z),
-            character_collider(data),
+            data.collider(),
             Visibility::Inherited,
             RigidBody::KinematicPositionBased,
             GravityScale(0.),
@@ -105,7 +96,8 @@ impl Character for Slime {
             },
             LockedAxes::ROTATION_LOCKED,
             Movement::default(),
-            JumpTimer::default(),
+            StatusEffects::default(),
+            ContactDamage,
             Blocking,
         )
     }
@@ -16,39 +16,39 @@
 
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
-use bevy_enhanced_input::prelude::*;
+use bevy_enhanced_input::prelude::{Press, *};
 use bevy_northstar::prelude::*;
 use bevy_rapier2d::prelude::*;
 
 use crate::{
     AppSystems, PausableSystems, Pause,
     characters::{
-        Character, CharacterAssets, CollisionData, CollisionHandle, JumpTimer, Movement, VisualMap,
-        animations::{self, AnimationController, AnimationState, Animations},
-        character_collider, setup_shadow, tick_jump_timer,
+        Character, CharacterAssets, CollisionData, CollisionHandle, DASH_DURATION_SECS, JumpTimer,
+        Movement, StatsData, StatsHandle, Visual, add_translation,
+        animations::{AnimationController, AnimationState},
+        definition::{CharacterData, CharacterPlugin},
+        stamina::{Stamina, regenerate_stamina},
+        status::{
+            ApplyStatusEffect, StatusEffect, StatusEffectKind, StatusEffects, StatusModifiers,
+        },
+        tick_jump_timer,
     },
     impl_character_assets,
     levels::{DEFAULT_Z, YSort, YSortOffset},
-    logging::{error::ERR_LOADING_TILE_DATA, warn::WARN_INCOMPLETE_COLLISION_DATA_FALLBACK},
+    logging::error::{ERR_LOADING_COLLISION_DATA, ERR_LOADING_STATS_DATA},
+    particles::{ParticleEffect, ParticleEmitter},
     screens::Screen,
+    settings::bindings::{InputBindings, PlayerAction},
 };
 
 pub(super) fn plugin(app: &mut App) {
-    // Insert Animation resource
-    app.insert_resource(Animations::<Player>::default());
+    // Add character plugin
+    // NOTE: The player definition is chosen in the character select menu.
+    app.add_plugins(CharacterPlugin::<Player>::selectable());
 
     // Add enhanced input plugin
     app.add_plugins(EnhancedInputPlugin);
 
-    // Setup player
-    app.add_systems(OnEnter(Screen::Gameplay), setup_shadow::<Player>);
-
-    // Animation setup
-    app.add_systems(
-        OnEnter(Screen::Gameplay),
-        animations::setup_animations::<Player, PlayerAssets>,
-    );
-
     // Jump or stop jump depending on timer
     app.add_systems(
         Update,
@@ -61,15 +61,33 @@ pub(super) fn plugin(app: &mut App) {
             .chain(),
     );
 
-    // Animation updates
+    // Tick jump buffers and dash timers
     app.add_systems(
         Update,
-        (
-            animations::update_animations::<Player>.after(animations::tick_animation_timer),
-            animations::update_animation_sounds::<Player, PlayerAssets>
-                .run_if(in_state(Screen::Gameplay)),
-        )
-            .chain()
+        (tick_jump_buffers, tick_dash_timers)
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+
+    // Stop dash depending on timer
+    app.add_systems(
+        Update,
+        limit_dash.after(tick_dash_timers).in_set(PausableSystems),
+    );
+
+    // Accelerate, decelerate and move player with its velocity or dash at a fixed timestep
+    app.add_systems(
+        FixedUpdate,
+        ((update_velocity, apply_velocity).chain(), apply_dash)
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(PausableSystems),
+    );
+
+    // Regenerate stamina
+    app.add_systems(
+        Update,
+        regenerate_stamina::<Player>
+            .run_if(in_state(Screen::Gameplay))
             .in_set(AppSystems::Update)
             .in_set(PausableSystems),
     );
@@ -79,50 +97,57 @@ pub(super) fn plugin(app: &mut App) {
     app.add_observer(apply_walk);
     app.add_observer(stop_walk);
     app.add_observer(set_jump);
+    app.add_observer(stop_jump);
+    app.add_observer(set_dash);
+    app.add_observer(set_sprint);
+    app.add_observer(stop_sprint);
 }
 
 /// Assets that are serialized from a ron file
 #[derive(AssetCollection, Resource, Reflect, Default)]
 pub(crate) struct PlayerAssets {
-    #[asset(key = "male.walk_sounds", collection(typed), optional)]
+    #[asset(key = "player.walk_sounds", collection(typed), optional)]
     pub(crate) walk_sounds: Option<Vec<Handle<AudioSource>>>,
 
-    #[asset(key = "male.jump_sounds", collection(typed), optional)]
+    #[asset(key = "player.jump_sounds", collection(typed), optional)]
     pub(crate) jump_sounds: Option<Vec<Handle<AudioSource>>>,
 
-    #[asset(key = "male.fall_sounds", collection(typed), optional)]
+    #[asset(key = "player.fall_sounds", collection(typed), optional)]
     pub(crate) fall_sounds: Option<Vec<Handle<AudioSource>>>,
 
-    #[asset(key = "male.image")]
+    #[asset(key = "player.image")]
     pub(crate) image: Handle<Image>,
 }
 impl_character_assets!(PlayerAssets);
 
-/// Walking speed of the player
-const WALK_SPEED: f32 = 80.;
+/// Directory that contains all player definitions
+pub(crate) const PLAYER_DEFINITIONS_DIR: &str = "data/characters/player";
+
+/// Player definitions that can be chosen in the character select menu
+#[derive(Resource, Default)]
+pub(crate) struct PlayerDefinitions(pub(crate) Vec<PlayerDefinition>);
+
+/// Player definition with an image that is loaded for previews
+pub(crate) struct PlayerDefinition {
+    pub(crate) handle: Handle<CharacterData<Player>>,
+    pub(crate) image: Option<Handle<Image>>,
+}
 
 /// Player marker
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 #[reflect(Component)]
 pub(crate) struct Player;
 impl Character for Player {
-    fn container_bundle(
-        &self,
-        data: &(Option<String>, Option<f32>, Option<f32>),
-        pos: Vec2,
-    ) -> impl Bundle {
-        let width = data.1.unwrap_or_else(|| {
-            warn_once!("{}", WARN_INCOMPLETE_COLLISION_DATA_FALLBACK);
-            24.
-        });
+    type Assets = PlayerAssets;
 
+    fn container_bundle(&self, data: &CollisionData<Self>, pos: Vec2) -> impl Bundle {
         (
             Name::new("Player"),
             Self,
             Transform::from_translation(pos.extend(DEFAULT_Z)),
             YSort(DEFAULT_Z),
-            YSortOffset(width / 4.),
-            character_collider(data),
+            YSortOffset(data.width() / 4.),
+            data.collider(),
             Visibility::Inherited,
             RigidBody::KinematicVelocityBased,
             GravityScale(0.),
@@ -132,30 +157,40 @@ impl Character for Player {
             },
             LockedAxes::ROTATION_LOCKED,
             Movement::default(),
+            StatusEffects::default(),
             Blocking,
-            actions!(
-                Self[
-                    (
-                        Action::<Walk>::new(),
-                        DeadZone::default(),
-                        SmoothNudge::default(),
-                        Scale::splat(WALK_SPEED),
-                        Bindings::spawn((
-                            Cardinal::arrows(),
-                            Cardinal::wasd_keys(),
-                            Axial::left_stick(),
-                        ))
-                    ),
-                    (
-                        Action::<Jump>::new(),
-                        bindings![KeyCode::Space, GamepadButton::South],
-                    ),
-                ]
-            ),
         )
     }
 }
 
+/// Input actions of the player with rebindable [`InputBindings`]
+///
+/// This is inserted when spawning the player so that rebound inputs are used.
+pub(crate) fn player_actions(bindings: &InputBindings) -> impl Bundle + use<> {
+    actions!(
+        Player[
+            (
+                Action::<Walk>::new(),
+                DeadZone::default(),
+                Bindings::spawn((bindings.walk_bindings(), Axial::left_stick()))
+            ),
+            (
+                Action::<Jump>::new(),
+                Bindings::spawn(bindings.button_bindings(PlayerAction::Jump)),
+            ),
+            (
+                Action::<Dash>::new(),
+                Press::default(),
+                Bindings::spawn(bindings.button_bindings(PlayerAction::Dash)),
+            ),
+            (
+                Action::<Sprint>::new(),
+                Bindings::spawn(bindings.button_bindings(PlayerAction::Sprint)),
+            ),
+        ]
+    )
+}
+
 /// Walk marker
 #[derive(Debug, InputAction)]
 #[action_output(Vec2)]
@@ -166,37 +201,50 @@ struct Walk;
 #[action_output(bool)]
 struct Jump;
 
-/// On a fired walk, set translation to the given input
+/// Dash marker
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+struct Dash;
+
+/// Sprint marker
+#[derive(Debug, InputAction)]
+#[action_output(bool)]
+struct Sprint;
+
+/// Marker for a player that is holding sprint
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+struct Sprinting;
+
+/// On a fired walk, set movement target to the given input
 fn apply_walk(
     event: On<Fire<Walk>>,
-    parent: Single<(Entity, &mut KinematicCharacterController, &mut Movement), With<Player>>,
+    parent: Single<(&Visual, &mut Movement, &StatusModifiers), With<Player>>,
     mut child_query: Query<&mut AnimationController, Without<Player>>,
     pause: Res<State<Pause>>,
-    time: Res<Time>,
-    visual_map: Res<VisualMap>,
 ) {
     // Return if game is paused
     if pause.get().0 {
         return;
     }
 
-    let (entity, mut character_controller, mut movement) = parent.into_inner();
+    let (visual, mut movement, modifiers) = parent.into_inner();
 
     // Extract `animation_controller` from `child_query`
-    let Some(visual) = visual_map.0.get(&entity) else {
-        return;
-    };
-    let Ok(mut animation_controller) = child_query.get_mut(*visual) else {
+    let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
         return;
     };
 
-    // Apply movement from input
-    movement.target = event.value * time.delta_secs();
-    character_controller.translation = Some(movement.target);
+    // Set movement target from input
+    movement.target = event.value.clamp_length_max(1.);
 
-    // Return if we are jumping
+    // Return if we are jumping, dashing, hurt or stunned
     let state = animation_controller.state;
-    if state == AnimationState::Jump || state == AnimationState::Fall {
+    if matches!(
+        state,
+        AnimationState::Jump | AnimationState::Fall | AnimationState::Dash | AnimationState::Hurt
+    ) || modifiers.stunned
+    {
         return;
     }
 
@@ -204,91 +252,251 @@ fn apply_walk(
     animation_controller.state = AnimationState::Walk;
 }
 
-/// On a completed walk, set translation to zero
+/// On a completed walk, reset movement target
+///
+/// The player then decelerates in [`update_velocity`].
 fn stop_walk(
     _: On<Complete<Walk>>,
-    parent: Single<(Entity, &mut KinematicCharacterController, &mut Movement), With<Player>>,
+    parent: Single<(&Visual, &mut Movement), With<Player>>,
     mut child_query: Query<&mut AnimationController, Without<Player>>,
-    visual_map: Res<VisualMap>,
 ) {
-    let (entity, mut character_controller, mut movement) = parent.into_inner();
+    let (visual, mut movement) = parent.into_inner();
 
     // Extract `animation_controller` from `child_query`
-    let Some(visual) = visual_map.0.get(&entity) else {
-        return;
-    };
-    let Ok(mut animation_controller) = child_query.get_mut(*visual) else {
+    let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
         return;
     };
 
     // Reset movement target
     movement.target = Vec2::ZERO;
 
-    // Return if we are jumping
+    // Return if we are jumping, dashing or hurt
     let state = animation_controller.state;
-    if state == AnimationState::Jump || state == AnimationState::Fall {
+    if matches!(
+        state,
+        AnimationState::Jump | AnimationState::Fall | AnimationState::Dash | AnimationState::Hurt
+    ) {
         return;
     }
 
-    // Stop movement
-    character_controller.translation = Some(movement.target);
+    // Set animation state
     animation_controller.state = AnimationState::Idle;
 }
 
-// On a fired jump, move player up
+/// Accelerate towards the movement target and decelerate without input
+///
+/// This runs in [`FixedUpdate`] so that movement does not depend on the frame rate.
+fn update_velocity(
+    parent: Single<
+        (
+            &Visual,
+            &mut Movement,
+            &mut Stamina,
+            &StatusModifiers,
+            Has<Sprinting>,
+        ),
+        With<Player>,
+    >,
+    child_query: Query<&AnimationController, Without<Player>>,
+    data: Res<Assets<StatsData<Player>>>,
+    handle: Res<StatsHandle<Player>>,
+    time: Res<Time>,
+) {
+    let (visual, mut movement, mut stamina, modifiers, sprinting) = parent.into_inner();
+
+    // Extract `animation_controller` from `child_query`
+    let Ok(animation_controller) = child_query.get(visual.entity()) else {
+        return;
+    };
+
+    // Stop if we are dashing, hurt or stunned since other systems move us then
+    let state = animation_controller.state;
+    if state == AnimationState::Dash || state == AnimationState::Hurt || modifiers.stunned {
+        movement.velocity = Vec2::ZERO;
+        return;
+    }
+
+    // Scale max speed if we are sprinting and have enough stamina
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_STATS_DATA);
+    let delta = time.delta_secs();
+    let speed_factor = if sprinting
+        && movement.target != Vec2::ZERO
+        && stamina.try_spend(data.sprint_stamina_cost_per_sec * delta)
+    {
+        data.sprint_speed_factor
+    } else {
+        1.
+    };
+    let target_velocity = movement.target * data.walk_speed * speed_factor * modifiers.speed_factor;
+
+    // Accelerate with input and decelerate without, with less control while jumping
+    let rate = if movement.target == Vec2::ZERO {
+        data.deceleration
+    } else {
+        data.acceleration
+    };
+    let control = if state == AnimationState::Jump || state == AnimationState::Fall {
+        data.air_control
+    } else {
+        1.
+    };
+    movement.velocity = movement
+        .velocity
+        .move_towards(target_velocity, rate * control * delta);
+}
+
+/// Move player with its velocity
+///
+/// This runs in [`FixedUpdate`] so that movement does not depend on the frame rate.
+fn apply_velocity(
+    parent: Single<
+        (
+            &Visual,
+            &mut KinematicCharacterController,
+            &Movement,
+            &StatusModifiers,
+        ),
+        With<Player>,
+    >,
+    child_query: Query<&AnimationController, Without<Player>>,
+    time: Res<Time<Fixed>>,
+) {
+    let (visual, mut character_controller, movement, modifiers) = parent.into_inner();
+
+    // Extract `animation_controller` from `child_query`
+    let Ok(animation_controller) = child_query.get(visual.entity()) else {
+        return;
+    };
+
+    // Return if we are dashing, hurt or stunned
+    let state = animation_controller.state;
+    if state == AnimationState::Dash || state == AnimationState::Hurt || modifiers.stunned {
+        return;
+    }
+
+    add_translation(
+        &mut character_controller,
+        movement.velocity * time.delta_secs(),
+    );
+}
+
+/// Marker for a player that is holding jump
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
+struct HoldingJump;
+
+/// Timer that keeps a jump that was pressed while airborne
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+struct JumpBuffer(Timer);
+
+// On a started jump, move player up or buffer the jump if we are airborne
 fn set_jump(
-    _: On<Fire<Jump>>,
-    parent: Single<Entity, With<Player>>,
+    _: On<Start<Jump>>,
+    parent: Single<
+        (
+            Entity,
+            &Visual,
+            &mut Movement,
+            &mut Stamina,
+            &StatusModifiers,
+        ),
+        With<Player>,
+    >,
     mut child_query: Query<&mut AnimationController, Without<Player>>,
     mut commands: Commands,
+    data: Res<Assets<StatsData<Player>>>,
+    handle: Res<StatsHandle<Player>>,
     pause: Res<State<Pause>>,
-    visual_map: Res<VisualMap>,
 ) {
     // Return if game is paused
     if pause.get().0 {
         return;
     }
 
-    let entity = parent.entity();
+    let (entity, visual, mut movement, mut stamina, modifiers) = parent.into_inner();
 
-    // Extract `animation_controller` from `child_query`
-    let Some(visual) = visual_map.0.get(&entity) else {
+    // Mark jump as held to extend it
+    commands.entity(entity).insert(HoldingJump);
+
+    // Return if we are stunned
+    if modifiers.stunned {
         return;
-    };
-    let Ok(mut animation_controller) = child_query.get_mut(*visual) else {
+    }
+
+    // Extract `animation_controller` from `child_query`
+    let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
         return;
     };
 
-    // Return if we are already jumping
+    // Buffer jump if we are already jumping
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_STATS_DATA);
     let state = animation_controller.state;
     if state == AnimationState::Jump || state == AnimationState::Fall {
+        commands
+            .entity(entity)
+            .insert(JumpBuffer(Timer::from_seconds(
+                data.jump.buffer_secs,
+                TimerMode::Once,
+            )));
+        return;
+    }
+
+    // Return if we are dashing or hurt
+    if state == AnimationState::Dash || state == AnimationState::Hurt {
+        return;
+    }
+
+    start_jump(
+        &mut commands,
+        entity,
+        &mut movement,
+        &mut stamina,
+        &mut animation_controller,
+        data,
+    );
+}
+
+/// On a completed jump, stop extending the jump
+fn stop_jump(_: On<Complete<Jump>>, parent: Single<Entity, With<Player>>, mut commands: Commands) {
+    commands.entity(parent.entity()).remove::<HoldingJump>();
+}
+
+/// Start a jump if we have enough stamina
+fn start_jump(
+    commands: &mut Commands,
+    entity: Entity,
+    movement: &mut Movement,
+    stamina: &mut Stamina,
+    animation_controller: &mut AnimationController,
+    data: &StatsData<Player>,
+) {
+    // Return if we do not have enough stamina
+    if !stamina.try_spend(data.jump_stamina_cost) {
         return;
     }
 
     // Set state to jump
-    commands.entity(entity).insert(JumpTimer::default());
+    movement.jump_peak = data.jump.height;
+    commands.entity(entity).insert(JumpTimer::new(&data.jump));
     animation_controller.state = AnimationState::Jump;
 }
 
-/// Jump height
-const JUMP_HEIGHT: f32 = 12.;
-
 /// Apply jump
 fn apply_jump(
-    parent: Single<(Entity, &mut Movement, &JumpTimer), With<Player>>,
+    parent: Single<(Entity, &Visual, &mut Movement, &JumpTimer, Has<HoldingJump>), With<Player>>,
     mut child_query: Query<(&AnimationController, &mut Transform), Without<Player>>,
     mut commands: Commands,
     data: Res<Assets<CollisionData<Player>>>,
     handle: Res<CollisionHandle<Player>>,
-    visual_map: Res<VisualMap>,
+    stats_data: Res<Assets<StatsData<Player>>>,
+    stats_handle: Res<StatsHandle<Player>>,
+    time: Res<Time>,
 ) {
-    let (entity, mut movement, timer) = parent.into_inner();
+    let (entity, visual, mut movement, timer, holding) = parent.into_inner();
 
     // Extract `animation_controller` from `child_query`
-    let Some(visual) = visual_map.0.get(&entity) else {
-        return;
-    };
-    let Ok((animation_controller, mut transform)) = child_query.get_mut(*visual) else {
+    let Ok((animation_controller, mut transform)) = child_query.get_mut(visual.entity()) else {
         return;
     };
 
@@ -299,45 +507,56 @@ fn apply_jump(
         return;
     }
 
-    // Apply visual jump or fall
-    let factor = if state == AnimationState::Jump {
-        1.0f32
-    } else {
-        -1.0f32
-    };
-    let eased_time = EasingCurve::new(0., 1., EaseFunction::QuadraticOut);
-    let eased_time = eased_time.sample_clamped(timer.0.fraction());
-    let target = JUMP_HEIGHT * factor * eased_time;
+    // Raise the highest point while jump is held during the rise
+    // NOTE: Holding for the whole rise reaches `JumpData::max_height`.
+    let stats = stats_data
+        .get(stats_handle.0.id())
+        .expect(ERR_LOADING_STATS_DATA);
+    let jump = &stats.jump;
+    if state == AnimationState::Jump && holding {
+        let rate = (jump.max_height - jump.height) / timer.0.duration().as_secs_f32();
+        movement.jump_peak = (movement.jump_peak + rate * time.delta_secs()).min(jump.max_height);
+    }
 
-    transform.translation.y += target - movement.jump_height;
-    movement.jump_height = target;
+    // Apply visual jump or fall
+    let height = jump.height_at(
+        movement.jump_peak,
+        timer.0.fraction(),
+        state == AnimationState::Fall,
+    );
+    transform.translation.y += height - movement.height;
+    movement.height = height;
 
     // Apply `YSortOffset` for jump
-    let y_sort_offset = if target < 0. {
-        JUMP_HEIGHT + target
-    } else {
-        target
-    };
-    let data = data.get(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
-    let width = data.width.unwrap_or_else(|| {
-        warn_once!("{}", WARN_INCOMPLETE_COLLISION_DATA_FALLBACK);
-        24.
-    });
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
     commands
         .entity(entity)
-        .insert(YSortOffset(width / 4. + y_sort_offset));
+        .insert(YSortOffset(data.width() / 4. + height));
 }
 
-/// Limit jump by setting fall after specific time and then switching to walk
+/// Limit jump by setting fall after the rise and then landing
+///
+/// A buffered jump is started right after landing.
 fn limit_jump(
-    parent: Single<(Entity, &mut Movement, &JumpTimer), With<Player>>,
+    parent: Single<
+        (
+            Entity,
+            &Visual,
+            &mut Movement,
+            &mut Stamina,
+            &JumpTimer,
+            Option<&JumpBuffer>,
+        ),
+        With<Player>,
+    >,
     mut child_query: Query<&mut AnimationController, Without<Player>>,
     mut commands: Commands,
     data: Res<Assets<CollisionData<Player>>>,
     handle: Res<CollisionHandle<Player>>,
-    visual_map: Res<VisualMap>,
+    stats_data: Res<Assets<StatsData<Player>>>,
+    stats_handle: Res<StatsHandle<Player>>,
 ) {
-    let (entity, mut movement, timer) = parent.into_inner();
+    let (entity, visual, mut movement, mut stamina, timer, buffer) = parent.into_inner();
 
     // Return if timer has not finished
     if !timer.0.just_finished() {
@@ -345,31 +564,230 @@ fn limit_jump(
     }
 
     // Extract `animation_controller` from `child_query`
-    let Some(visual) = visual_map.0.get(&entity) else {
-        return;
-    };
-    let Ok(mut animation_controller) = child_query.get_mut(*visual) else {
+    let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
         return;
     };
 
-    // Reset jump height
-    movement.jump_height = 0.;
+    let stats = stats_data
+        .get(stats_handle.0.id())
+        .expect(ERR_LOADING_STATS_DATA);
 
     // Set animation states
     match animation_controller.state {
         AnimationState::Jump => {
-            commands.entity(entity).insert(JumpTimer::default());
+            commands.entity(entity).insert(JumpTimer::new(&stats.jump));
             animation_controller.state = AnimationState::Fall;
         }
         AnimationState::Fall => {
-            let data = data.get(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
-            let width = data.width.unwrap_or_else(|| {
-                warn_once!("{}", WARN_INCOMPLETE_COLLISION_DATA_FALLBACK);
-                24.
-            });
-            commands.entity(entity).insert(YSortOffset(width / 4.));
-            animation_controller.state = AnimationState::Idle
+            // Land
+            movement.height = 0.;
+            let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
+            commands
+                .entity(entity)
+                .remove::<(JumpTimer, JumpBuffer)>()
+                .insert(YSortOffset(data.width() / 4.));
+            animation_controller.state = if movement.target == Vec2::ZERO {
+                AnimationState::Idle
+            } else {
+                AnimationState::Walk
+            };
+
+            // Start buffered jump
+            if buffer.is_some_and(|buffer| !buffer.0.is_finished()) {
+                start_jump(
+                    &mut commands,
+                    entity,
+                    &mut movement,
+                    &mut stamina,
+                    &mut animation_controller,
+                    stats,
+                );
+            }
         }
         _ => (),
     }
 }
+
+/// Tick jump buffers
+fn tick_jump_buffers(mut query: Query<&mut JumpBuffer>, time: Res<Time>) {
+    for mut buffer in &mut query {
+        buffer.0.tick(time.delta());
+    }
+}
+
+/// Timer that tracks dashing
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+struct DashTimer(Timer);
+impl Default for DashTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(DASH_DURATION_SECS, TimerMode::Once))
+    }
+}
+
+/// Timer that tracks the cooldown between dashes
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+struct DashCooldown(Timer);
+
+/// On a fired dash, start dashing in the current walk direction
+fn set_dash(
+    _: On<Fire<Dash>>,
+    parent: Single<
+        (
+            Entity,
+            &Visual,
+            &mut Movement,
+            &mut Stamina,
+            &StatusModifiers,
+            Option<&DashCooldown>,
+        ),
+        With<Player>,
+    >,
+    mut child_query: Query<&mut AnimationController, Without<Player>>,
+    mut commands: Commands,
+    mut msgs: MessageWriter<ApplyStatusEffect>,
+    data: Res<Assets<StatsData<Player>>>,
+    handle: Res<StatsHandle<Player>>,
+    pause: Res<State<Pause>>,
+) {
+    // Return if game is paused
+    if pause.get().0 {
+        return;
+    }
+
+    let (entity, visual, mut movement, mut stamina, modifiers, cooldown) = parent.into_inner();
+
+    // Return if dash is on cooldown or we are stunned
+    if cooldown.is_some_and(|cooldown| !cooldown.0.is_finished()) || modifiers.stunned {
+        return;
+    }
+
+    // Extract `animation_controller` from `child_query`
+    let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
+        return;
+    };
+
+    // Return if we are jumping, hurt or already dashing
+    let state = animation_controller.state;
+    if matches!(
+        state,
+        AnimationState::Jump | AnimationState::Fall | AnimationState::Dash | AnimationState::Hurt
+    ) {
+        return;
+    }
+
+    // Return if we are not walking in any direction
+    let direction = movement.target.normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return;
+    }
+
+    // Return if we do not have enough stamina
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_STATS_DATA);
+    if !stamina.try_spend(data.dash_stamina_cost) {
+        return;
+    }
+
+    // Set state to dash, leave a trail and become invulnerable for its duration
+    movement.dash_direction = direction;
+    commands.entity(entity).insert((
+        DashTimer::default(),
+        DashCooldown(Timer::from_seconds(
+            data.dash_cooldown_secs,
+            TimerMode::Once,
+        )),
+        ParticleEmitter::new(ParticleEffect::DashTrail),
+    ));
+    msgs.write(ApplyStatusEffect {
+        target: entity,
+        effect: StatusEffect::new(StatusEffectKind::Invulnerability, 1., DASH_DURATION_SECS),
+    });
+    animation_controller.state = AnimationState::Dash;
+}
+
+/// Apply dash
+///
+/// This runs in [`FixedUpdate`] so that movement does not depend on the frame rate.
+fn apply_dash(
+    parent: Single<
+        (
+            &mut KinematicCharacterController,
+            &Movement,
+            &StatusModifiers,
+        ),
+        (With<Player>, With<DashTimer>),
+    >,
+    data: Res<Assets<StatsData<Player>>>,
+    handle: Res<StatsHandle<Player>>,
+    time: Res<Time<Fixed>>,
+) {
+    let (mut character_controller, movement, modifiers) = parent.into_inner();
+
+    // Apply movement in dash direction
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_STATS_DATA);
+    add_translation(
+        &mut character_controller,
+        movement.dash_direction * data.dash_speed * modifiers.speed_factor * time.delta_secs(),
+    );
+}
+
+/// Limit dash by removing it after specific time and then switching to walk or idle
+fn limit_dash(
+    parent: Single<(Entity, &Visual, &mut Movement, &DashTimer), With<Player>>,
+    mut child_query: Query<&mut AnimationController, Without<Player>>,
+    mut commands: Commands,
+) {
+    let (entity, visual, mut movement, timer) = parent.into_inner();
+
+    // Return if timer has not finished
+    if !timer.0.just_finished() {
+        return;
+    }
+
+    // Extract `animation_controller` from `child_query`
+    let Ok(mut animation_controller) = child_query.get_mut(visual.entity()) else {
+        return;
+    };
+
+    // Reset dash direction and remove trail
+    movement.dash_direction = Vec2::ZERO;
+    commands
+        .entity(entity)
+        .remove::<(DashTimer, ParticleEmitter)>();
+
+    // Set animation state depending on whether we are still walking
+    animation_controller.state = if movement.target == Vec2::ZERO {
+        AnimationState::Idle
+    } else {
+        AnimationState::Walk
+    };
+}
+
+/// On a fired sprint, mark player as sprinting
+fn set_sprint(_: On<Fire<Sprint>>, parent: Single<Entity, With<Player>>, mut commands: Commands) {
+    commands.entity(parent.entity()).insert(Sprinting);
+}
+
+/// On a completed sprint, stop marking player as sprinting
+fn stop_sprint(
+    _: On<Complete<Sprint>>,
+    parent: Single<Entity, With<Player>>,
+    mut commands: Commands,
+) {
+    commands.entity(parent.entity()).remove::<Sprinting>();
+}
+
+/// Tick dash timers
+fn tick_dash_timers(
+    mut dash_query: Query<&mut DashTimer>,
+    mut cooldown_query: Query<&mut DashCooldown>,
+    time: Res<Time>,
+) {
+    for mut timer in &mut dash_query {
+        timer.0.tick(time.delta());
+    }
+    for mut timer in &mut cooldown_query {
+        timer.0.tick(time.delta());
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ * File: stamina.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Stamina that is spent by actions and regenerates over time.
+
+use bevy::prelude::*;
+
+use crate::{
+    AppSystems, PausableSystems,
+    characters::{Character, StatsData, StatsHandle},
+    logging::error::ERR_LOADING_STATS_DATA,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Tick stamina regeneration delay
+    app.add_systems(
+        Update,
+        tick_stamina_timer
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+}
+
+/// Stamina of a character
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct Stamina {
+    pub(crate) current: f32,
+    pub(crate) max: f32,
+    /// Delay after spending stamina before it starts to regenerate
+    regen_delay: Timer,
+}
+impl Stamina {
+    pub(crate) fn new(max: f32, regen_delay_secs: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen_delay: Timer::from_seconds(regen_delay_secs, TimerMode::Once),
+        }
+    }
+
+    /// Spend stamina if enough is available.
+    ///
+    /// Returns `true` if stamina has been spent.
+    /// Returns `false` if there is not enough stamina.
+    pub(crate) fn try_spend(&mut self, cost: f32) -> bool {
+        if self.current < cost {
+            return false;
+        }
+
+        self.current -= cost;
+        self.regen_delay.reset();
+        true
+    }
+
+    /// Current stamina as a fraction of maximum stamina.
+    pub(crate) fn fraction(&self) -> f32 {
+        if self.max <= 0. {
+            return 0.;
+        }
+        (self.current / self.max).clamp(0., 1.)
+    }
+}
+
+/// Regenerate [`Stamina`] after its regeneration delay
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn regenerate_stamina<T>(
+    mut query: Query<&mut Stamina, With<T>>,
+    data: Res<Assets<StatsData<T>>>,
+    handle: Res<StatsHandle<T>>,
+    time: Res<Time>,
+) where
+    T: Character,
+{
+    // Get data from `StatsData` with `StatsHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_STATS_DATA);
+
+    for mut stamina in &mut query {
+        // Continue if stamina is full or we are still waiting for regeneration
+        if stamina.current >= stamina.max || !stamina.regen_delay.is_finished() {
+            continue;
+        }
+
+        stamina.current =
+            (stamina.current + data.stamina_regen_per_sec * time.delta_secs()).min(stamina.max);
+    }
+}
+
+/// Tick stamina regeneration delay
+fn tick_stamina_timer(mut query: Query<&mut Stamina>, time: Res<Time>) {
+    for mut stamina in &mut query {
+        stamina.regen_delay.tick(time.delta());
+    }
+}
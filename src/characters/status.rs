@@ -0,0 +1,319 @@
+/*
+ * File: status.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Status effects of characters like slow, stun, invulnerability, speed boost and poison.
+//!
+//! Other systems should only query [`StatusModifiers`] which aggregates all active [`StatusEffects`].
+
+use bevy::prelude::*;
+
+use crate::{AppSystems, PausableSystems, characters::damage::Health};
+
+pub(super) fn plugin(app: &mut App) {
+    // Add messages for status effects
+    app.add_message::<ApplyStatusEffect>();
+    app.add_message::<StatusEffectApplied>();
+    app.add_message::<StatusEffectExpired>();
+
+    // Tick status effect timers
+    app.add_systems(
+        Update,
+        tick_status_effects
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+
+    // Apply, expire and aggregate status effects
+    app.add_systems(
+        Update,
+        (
+            apply_status_effects,
+            expire_status_effects,
+            update_status_modifiers,
+            apply_poison,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+}
+
+/// Kind of a status effect
+#[derive(serde::Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub(crate) enum StatusEffectKind {
+    /// Reduces movement speed by its strength as a fraction
+    Slow,
+    /// Ignores input and pauses animations
+    Stun,
+    /// Ignores damage
+    Invulnerability,
+    /// Increases movement speed by its strength as a fraction
+    SpeedBoost,
+    /// Deals its strength as damage per second
+    Poison,
+}
+impl StatusEffectKind {
+    /// Name that is displayed in the HUD
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Slow => "Slowed",
+            Self::Stun => "Stunned",
+            Self::Invulnerability => "Invulnerable",
+            Self::SpeedBoost => "Speed Boost",
+            Self::Poison => "Poisoned",
+        }
+    }
+
+    /// Rule that is used if an effect of this kind is applied while it is already active
+    fn stacking(self) -> StatusStacking {
+        match self {
+            Self::Slow | Self::Stun | Self::Invulnerability | Self::SpeedBoost => {
+                StatusStacking::Refresh
+            }
+            Self::Poison => StatusStacking::Stack(3),
+        }
+    }
+}
+
+/// Rule for applying a status effect that is already active
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum StatusStacking {
+    /// Keep a single instance with the highest strength and the longest remaining duration
+    Refresh,
+    /// Add independent instances up to a maximum and replace the one closest to expiring after that
+    Stack(usize),
+}
+
+/// A single active status effect
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub(crate) struct StatusEffect {
+    pub(crate) kind: StatusEffectKind,
+    pub(crate) strength: f32,
+    timer: Timer,
+}
+impl StatusEffect {
+    pub(crate) fn new(kind: StatusEffectKind, strength: f32, duration_secs: f32) -> Self {
+        Self {
+            kind,
+            strength,
+            timer: Timer::from_seconds(duration_secs, TimerMode::Once),
+        }
+    }
+
+    /// Remaining duration in seconds
+    fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+}
+
+/// Status effect deserialized from a ron file
+#[derive(serde::Deserialize, Clone, Debug, PartialEq, Reflect)]
+pub(crate) struct StatusEffectData {
+    pub(crate) kind: StatusEffectKind,
+    pub(crate) strength: f32,
+    pub(crate) duration_secs: f32,
+}
+impl StatusEffectData {
+    /// Status effect with a new timer
+    pub(crate) fn effect(&self) -> StatusEffect {
+        StatusEffect::new(self.kind, self.strength, self.duration_secs)
+    }
+}
+
+/// Active status effects of a character
+#[derive(Component, Default, Debug, Clone, PartialEq, Reflect)]
+#[require(StatusModifiers)]
+#[reflect(Component)]
+pub(crate) struct StatusEffects(Vec<StatusEffect>);
+impl StatusEffects {
+    /// Whether an effect of `kind` is active
+    pub(crate) fn contains(&self, kind: StatusEffectKind) -> bool {
+        self.0.iter().any(|effect| effect.kind == kind)
+    }
+}
+
+/// Aggregated modifiers from all active [`StatusEffects`] of a character
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct StatusModifiers {
+    /// Factor for movement and animation speed
+    pub(crate) speed_factor: f32,
+    pub(crate) stunned: bool,
+    pub(crate) invulnerable: bool,
+    pub(crate) damage_per_sec: f32,
+}
+impl Default for StatusModifiers {
+    fn default() -> Self {
+        Self {
+            speed_factor: 1.,
+            stunned: false,
+            invulnerable: false,
+            damage_per_sec: 0.,
+        }
+    }
+}
+
+/// Message that is written to apply a status effect to a character
+#[derive(Message, Debug, Clone)]
+pub(crate) struct ApplyStatusEffect {
+    pub(crate) target: Entity,
+    pub(crate) effect: StatusEffect,
+}
+
+/// Message that is written after a status effect has been applied to a character
+#[derive(Message, Debug, Clone, Copy)]
+pub(crate) struct StatusEffectApplied {
+    pub(crate) target: Entity,
+    pub(crate) kind: StatusEffectKind,
+}
+
+/// Message that is written after a status effect of a character has expired
+#[derive(Message, Debug, Clone, Copy)]
+pub(crate) struct StatusEffectExpired {
+    pub(crate) target: Entity,
+    pub(crate) kind: StatusEffectKind,
+}
+
+/// Apply status effects from [`ApplyStatusEffect`] with respect to [`StatusStacking`]
+fn apply_status_effects(
+    mut query: Query<&mut StatusEffects>,
+    mut apply_msgs: MessageReader<ApplyStatusEffect>,
+    mut applied_msgs: MessageWriter<StatusEffectApplied>,
+) {
+    for msg in apply_msgs.read() {
+        let Ok(mut effects) = query.get_mut(msg.target) else {
+            continue;
+        };
+        let effect = msg.effect.clone();
+        let kind = effect.kind;
+
+        match kind.stacking() {
+            StatusStacking::Refresh => {
+                if let Some(active) = effects.0.iter_mut().find(|active| active.kind == kind) {
+                    // Keep highest strength and longest remaining duration
+                    active.strength = active.strength.max(effect.strength);
+                    if effect.remaining_secs() > active.remaining_secs() {
+                        active.timer = effect.timer;
+                    }
+                } else {
+                    effects.0.push(effect);
+                }
+            }
+            StatusStacking::Stack(max) => {
+                let count = effects
+                    .0
+                    .iter()
+                    .filter(|active| active.kind == kind)
+                    .count();
+                if count < max {
+                    effects.0.push(effect);
+                } else if let Some(oldest) = effects
+                    .0
+                    .iter_mut()
+                    .filter(|active| active.kind == kind)
+                    .min_by(|a, b| a.remaining_secs().total_cmp(&b.remaining_secs()))
+                {
+                    // Replace instance that is closest to expiring
+                    *oldest = effect;
+                }
+            }
+        }
+
+        applied_msgs.write(StatusEffectApplied {
+            target: msg.target,
+            kind,
+        });
+    }
+}
+
+/// Remove finished status effects and write [`StatusEffectExpired`]
+fn expire_status_effects(
+    mut query: Query<(Entity, &mut StatusEffects)>,
+    mut msgs: MessageWriter<StatusEffectExpired>,
+) {
+    for (entity, mut effects) in &mut query {
+        // Continue if no effect has finished to avoid triggering change detection
+        if !effects.0.iter().any(|effect| effect.timer.is_finished()) {
+            continue;
+        }
+
+        effects.0.retain(|effect| {
+            if !effect.timer.is_finished() {
+                return true;
+            }
+            msgs.write(StatusEffectExpired {
+                target: entity,
+                kind: effect.kind,
+            });
+            false
+        });
+    }
+}
+
+/// Aggregate [`StatusEffects`] into [`StatusModifiers`]
+///
+/// Slows and speed boosts of the same kind do not multiply, only the strongest one is used.
+fn update_status_modifiers(
+    mut query: Query<(&StatusEffects, &mut StatusModifiers), Changed<StatusEffects>>,
+) {
+    for (effects, mut modifiers) in &mut query {
+        let strongest = |kind: StatusEffectKind| {
+            effects
+                .0
+                .iter()
+                .filter(|effect| effect.kind == kind)
+                .map(|effect| effect.strength)
+                .fold(0., f32::max)
+        };
+
+        *modifiers = StatusModifiers {
+            speed_factor: ((1. - strongest(StatusEffectKind::Slow))
+                * (1. + strongest(StatusEffectKind::SpeedBoost)))
+            .max(0.),
+            stunned: effects.contains(StatusEffectKind::Stun),
+            invulnerable: effects.contains(StatusEffectKind::Invulnerability),
+            damage_per_sec: effects
+                .0
+                .iter()
+                .filter(|effect| effect.kind == StatusEffectKind::Poison)
+                .map(|effect| effect.strength)
+                .sum(),
+        };
+    }
+}
+
+/// Apply damage over time from [`StatusModifiers`] to [`Health`]
+fn apply_poison(mut query: Query<(&mut Health, &StatusModifiers)>, time: Res<Time>) {
+    for (mut health, modifiers) in &mut query {
+        // Continue if there is no damage over time
+        if modifiers.invulnerable || modifiers.damage_per_sec <= 0. {
+            continue;
+        }
+
+        health.current -= modifiers.damage_per_sec * time.delta_secs();
+    }
+}
+
+/// Tick status effect timers
+///
+/// Change detection is only triggered if an effect has finished, so that [`update_status_modifiers`]
+/// does not run every frame.
+fn tick_status_effects(mut query: Query<&mut StatusEffects>, time: Res<Time>) {
+    for mut effects in &mut query {
+        let mut finished = false;
+        for effect in &mut effects.bypass_change_detection().0 {
+            effect.timer.tick(time.delta());
+            finished |= effect.timer.just_finished();
+        }
+
+        if finished {
+            effects.set_changed();
+        }
+    }
+}
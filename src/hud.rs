@@ -0,0 +1,156 @@
+/*
+ * File: hud.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Heads-up display that is visible during gameplay.
+
+use bevy::prelude::*;
+
+use crate::{
+    characters::{
+        damage::Health,
+        player::Player,
+        stamina::Stamina,
+        status::{StatusEffectApplied, StatusEffectExpired, StatusEffectKind, StatusEffects},
+    },
+    screens::Screen,
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Spawn HUD
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_hud);
+
+    // Update HUD
+    app.add_systems(
+        Update,
+        (update_health_bar, update_stamina_bar, update_status_labels)
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+/// Health bar fill marker
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct HealthBarFill;
+
+/// Stamina bar fill marker
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct StaminaBarFill;
+
+/// Container for [`StatusLabel`]s
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct StatusList;
+
+/// Label that displays an active status effect of the player
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct StatusLabel(StatusEffectKind);
+
+/// Spawn HUD
+fn spawn_hud(mut commands: Commands) {
+    commands.spawn((
+        Name::new("HUD"),
+        Node {
+            position_type: PositionType::Absolute,
+            left: px(20),
+            top: px(20),
+            flex_direction: FlexDirection::Column,
+            row_gap: px(10),
+            ..default()
+        },
+        // Don't block picking events for other UI roots.
+        Pickable::IGNORE,
+        DespawnOnExit(Screen::Gameplay),
+        children![
+            widgets::common::bar(
+                "Health Bar",
+                (
+                    HealthBarFill,
+                    BackgroundColor(ui_palette::HEALTH_BAR_FILL.into())
+                ),
+            ),
+            widgets::common::bar(
+                "Stamina Bar",
+                (
+                    StaminaBarFill,
+                    BackgroundColor(ui_palette::STAMINA_BAR_FILL.into())
+                ),
+            ),
+            (
+                Name::new("Status List"),
+                StatusList,
+                Node {
+                    column_gap: px(10),
+                    ..default()
+                },
+            ),
+        ],
+    ));
+}
+
+/// Update health bar to display current health
+fn update_health_bar(
+    mut fill: Single<&mut Node, With<HealthBarFill>>,
+    health: Single<&Health, (With<Player>, Changed<Health>)>,
+) {
+    fill.width = percent(100. * health.fraction());
+}
+
+/// Update stamina bar to display current stamina
+fn update_stamina_bar(
+    mut fill: Single<&mut Node, With<StaminaBarFill>>,
+    stamina: Single<&Stamina, (With<Player>, Changed<Stamina>)>,
+) {
+    fill.width = percent(100. * stamina.fraction());
+}
+
+/// Add and remove [`StatusLabel`]s if status effects of the player are applied or expire
+fn update_status_labels(
+    list: Single<Entity, With<StatusList>>,
+    player: Single<(Entity, &StatusEffects), With<Player>>,
+    labels: Query<(Entity, &StatusLabel)>,
+    mut applied_msgs: MessageReader<StatusEffectApplied>,
+    mut expired_msgs: MessageReader<StatusEffectExpired>,
+    mut commands: Commands,
+) {
+    let (player, effects) = player.into_inner();
+
+    // Add labels for newly applied effects
+    // NOTE: Labels that are spawned in this loop are not in `labels` yet, so we track them separately.
+    let mut spawned = Vec::new();
+    for msg in applied_msgs.read() {
+        // Continue if the effect is not on the player or already displayed
+        if msg.target != player
+            || spawned.contains(&msg.kind)
+            || labels.iter().any(|(_, label)| label.0 == msg.kind)
+        {
+            continue;
+        }
+
+        commands.entity(list.entity()).with_child((
+            widgets::common::label(msg.kind.name()),
+            StatusLabel(msg.kind),
+        ));
+        spawned.push(msg.kind);
+    }
+
+    // Remove labels of expired effects
+    for msg in expired_msgs.read() {
+        // Continue if the effect is not on the player or still active from another instance
+        if msg.target != player || effects.contains(msg.kind) {
+            continue;
+        }
+
+        for (entity, _) in labels.iter().filter(|(_, label)| label.0 == msg.kind) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
@@ -29,6 +29,8 @@ pub(super) fn plugin(app: &mut App) {
 
 /// Z-level for the level
 pub(crate) const LEVEL_Z: f32 = 1.;
+/// Z-level for decals on the ground
+pub(crate) const DECAL_Z: f32 = 5.;
 /// Z-level for shadows
 pub(crate) const SHADOW_Z: f32 = 9.;
 /// Z-level for any foreground object
@@ -45,6 +47,7 @@ where
     Self: AssetCollection + Resource + Default + Reflectable,
 {
     fn get_music(&self) -> &Option<Vec<Handle<AudioSource>>>;
+    fn get_intense_music(&self) -> &Option<Vec<Handle<AudioSource>>>;
     fn get_tile_set(&self) -> &Handle<Image>;
 }
 #[macro_export]
@@ -54,6 +57,9 @@ macro_rules! impl_level_assets {
             fn get_music(&self) -> &Option<Vec<Handle<AudioSource>>> {
                 &self.music
             }
+            fn get_intense_music(&self) -> &Option<Vec<Handle<AudioSource>>> {
+                &self.intense_music
+            }
             fn get_tile_set(&self) -> &Handle<Image> {
                 &self.tile_set
             }
@@ -12,21 +12,26 @@
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 use bevy_prng::WyRand;
-use rand::{Rng as _, seq::IndexedRandom};
+use rand::Rng as _;
 
 use crate::{
-    audio::music,
+    audio::director::{MusicDirector, music_director},
     characters::{
-        Character as _, CollisionData, CollisionHandle, Shadow, VisualMap,
+        Character as _, CollisionData, CollisionHandle, Shadow, StatsData, StatsHandle,
         animations::{ANIMATION_DELAY_RANGE, AnimationRng, Animations},
         npc::Slime,
-        player::Player,
+        player::{Player, player_actions},
+        stamina::Stamina,
     },
     impl_level_assets,
     levels::{LEVEL_Z, Level, LevelAssets, LevelRng},
-    logging::{error::ERR_LOADING_COLLISION_DATA, warn::WARN_INCOMPLETE_ASSET_DATA},
+    logging::{
+        error::{ERR_LOADING_COLLISION_DATA, ERR_LOADING_STATS_DATA},
+        warn::WARN_INCOMPLETE_ASSET_DATA,
+    },
     procgen::{ProcGenController, ProcGenerated},
     screens::Screen,
+    settings::Settings,
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -40,6 +45,8 @@ pub(super) fn plugin(app: &mut App) {
 pub(crate) struct OverworldAssets {
     #[asset(key = "overworld.music", collection(typed), optional)]
     music: Option<Vec<Handle<AudioSource>>>,
+    #[asset(key = "overworld.intense_music", collection(typed), optional)]
+    intense_music: Option<Vec<Handle<AudioSource>>>,
 
     #[asset(key = "overworld.tile_set")]
     pub(crate) tile_set: Handle<Image>,
@@ -67,16 +74,20 @@ pub(crate) fn spawn_overworld(
     mut animation_rng: Single<&mut WyRand, (With<AnimationRng>, Without<LevelRng>)>,
     mut level_rng: Single<&mut WyRand, (With<LevelRng>, Without<AnimationRng>)>,
     mut commands: Commands,
-    mut visual_map: ResMut<VisualMap>,
     animations: Res<Animations<Player>>,
     data: Res<Assets<CollisionData<Player>>>,
     handle: Res<CollisionHandle<Player>>,
     level_assets: Res<OverworldAssets>,
+    settings: Res<Settings>,
     shadow: Res<Shadow<Player>>,
+    stats_data: Res<Assets<StatsData<Player>>>,
+    stats_handle: Res<StatsHandle<Player>>,
 ) {
-    // Get data from `CollisionData` with `CollisionHandle`
+    // Get data from `CollisionData` with `CollisionHandle` and `StatsData` with `StatsHandle`
     let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
-    let data = (data.shape.clone(), data.width, data.height);
+    let stats = stats_data
+        .get(stats_handle.0.id())
+        .expect(ERR_LOADING_STATS_DATA);
 
     let level = commands
         .spawn((
@@ -88,31 +99,37 @@ pub(crate) fn spawn_overworld(
         ))
         .id();
 
-    // Spawn music
-    if let Some(level_music) = level_assets
-        .get_music()
-        .clone()
-        .unwrap_or_else(|| {
-            warn_once!("{}", WARN_INCOMPLETE_ASSET_DATA);
-            Vec::default()
-        })
-        .choose(level_rng.as_mut())
-        .cloned()
-    {
-        commands.entity(level).with_children(|commands| {
-            commands.spawn((Name::new("Gameplay Music"), music(level_music)));
-        });
-    }
+    // Spawn music director starting with a random track
+    let calm = level_assets.get_music().clone().unwrap_or_else(|| {
+        warn_once!("{}", WARN_INCOMPLETE_ASSET_DATA);
+        Vec::default()
+    });
+    let intense = level_assets.get_intense_music().clone().unwrap_or_default();
+    let index = level_rng.random_range(0..calm.len().max(1));
+    commands
+        .entity(level)
+        .with_child(music_director(MusicDirector::new(calm, intense, index)));
 
     // Spawn player
     let player = Player::spawn(
         &mut commands,
-        &mut visual_map,
-        &data,
+        data,
+        Some(stats),
         PLAYER_POS,
         &animations,
         &shadow,
         animation_rng.random_range(ANIMATION_DELAY_RANGE),
     );
     commands.entity(level).add_child(player);
+
+    // Add input actions with bindings from settings
+    commands
+        .entity(player)
+        .insert(player_actions(&settings.bindings));
+
+    // Add stamina to player from stats
+    commands.entity(player).insert(Stamina::new(
+        stats.max_stamina,
+        stats.stamina_regen_delay_secs,
+    ));
 }
@@ -7,15 +7,36 @@
  * URL: https://www.apache.org/licenses/LICENSE-2.0
  */
 
+/// Error message if loading character data failed
+pub(crate) const ERR_LOADING_CHARACTER_DATA: &str =
+    "Could not load character data. The file is probably missing.";
+/// Error message if a character data file could not be read or parsed
+pub(crate) const ERR_FAILED_CHARACTER_DATA: &str =
+    "Could not read or parse character data. Check the syntax of the file:";
 /// Error message if loading collision data failed
 pub(crate) const ERR_LOADING_COLLISION_DATA: &str =
     "Could not load collision data. The file is probably missing.";
+/// Error message if collision data contains an invalid shape
+pub(crate) const ERR_INVALID_COLLISION_DATA: &str =
+    "The loaded collision data contains an invalid shape. Check the sizes and points in the file:";
+/// Error message if colliders are built from collision data that has not been validated
+pub(crate) const ERR_UNVALIDATED_COLLISION_DATA: &str =
+    "Colliders have been built from collision data that has not been validated. This is a bug.";
 /// Error message if loading animation data failed
 pub(crate) const ERR_LOADING_ANIMATION_DATA: &str =
     "Could not load animation data. The file is probably missing.";
+/// Error message if loading stats data failed
+pub(crate) const ERR_LOADING_STATS_DATA: &str =
+    "Could not load stats data. The file is probably missing.";
+/// Error message if a player definition does not contain stats data
+pub(crate) const ERR_MISSING_PLAYER_STATS_DATA: &str =
+    "The loaded player definition does not contain stats. Add stats to the file:";
 /// Error message if loading tile data failed
 pub(crate) const ERR_LOADING_TILE_DATA: &str =
     "Could not load tile data. The file is probably missing.";
+/// Error message if loading particle data failed
+pub(crate) const ERR_LOADING_PARTICLE_DATA: &str =
+    "Could not load particle data. The file is probably missing.";
 
 /// Error message if sprite image is not loaded
 pub(crate) const ERR_SPRITE_IMAGE_NOT_LOADED: &str =
@@ -25,9 +46,6 @@ pub(crate) const ERR_SPRITE_IMAGE_NOT_LOADED: &str =
 /// Since only the idle animation is required, the error message includes that.
 pub(crate) const ERR_INVALID_REQUIRED_ANIMATION_DATA: &str =
     "The loaded animation data for required idle animation is invalid or incomplete.";
-/// Error message if loaded animation has not been initialized
-pub(crate) const ERR_UNINITIALIZED_REQUIRED_ANIMATION: &str =
-    "The requested animation has not been initialized.";
 
 /// Error message if an error has been encountered while calculating minimum chunk pos
 pub(crate) const ERR_INVALID_MINIMUM_CHUNK_POS: &str =
@@ -9,12 +9,27 @@
 
 //! This stores warning messages
 
-/// Warning on incomplete collision data
-pub(crate) const WARN_INCOMPLETE_COLLISION_DATA_FALLBACK: &str =
-    "The loaded collision data is incomplete. Using fallback ball collider.";
+/// Warning on invalid collision data while hot-reloading
+pub(crate) const WARN_INVALID_COLLISION_DATA_RELOAD: &str = "The reloaded collision data contains an invalid shape. Keeping previous collision data for file:";
 /// Warning on incomplete animation data
 pub(crate) const WARN_INCOMPLETE_ANIMATION_DATA: &str = "The loaded animation data is incomplete.";
+/// Warning on atlas dimensions that do not match the image
+pub(crate) const WARN_INVALID_ANIMATION_ATLAS: &str =
+    "The atlas dimensions in the loaded animation data do not evenly divide the image.";
+/// Warning on animation clip that does not fit into the atlas or has no interval
+pub(crate) const WARN_INVALID_ANIMATION_CLIP: &str =
+    "The loaded animation data contains an invalid clip. Skipping clip for state:";
+/// Warning on animation event or sound frame that is not part of its clip
+pub(crate) const WARN_INVALID_ANIMATION_EVENT_FRAME: &str = "The loaded animation data contains an event outside of its clip. Skipping event for state and frame:";
 /// Warning on incomplete asset data
 pub(crate) const WARN_INCOMPLETE_ASSET_DATA: &str = "The loaded asset data is incomplete.";
 /// Warning on incomplete tile data
 pub(crate) const WARN_INCOMPLETE_TILE_DATA: &str = "Missing some tile data for level.";
+/// Warning on particle effect without emitter data
+pub(crate) const WARN_MISSING_PARTICLE_EMITTER: &str =
+    "The loaded particle data has no emitter for effect:";
+/// Warning on settings that can not be deserialized
+pub(crate) const WARN_INVALID_SETTINGS: &str =
+    "The stored settings are invalid. Falling back to default settings.";
+/// Warning on settings that can not be saved
+pub(crate) const WARN_SAVING_SETTINGS: &str = "Failed to save settings.";
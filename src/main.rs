@@ -20,21 +20,32 @@ mod audio;
 mod characters;
 #[cfg(feature = "dev")]
 mod dev_tools;
+mod hud;
 mod levels;
 mod logging;
 mod menus;
+mod particles;
 mod procgen;
 mod screens;
+mod settings;
 mod theme;
 
-use bevy::{asset::AssetMetaCheck, color::palettes::tailwind, prelude::*, window::WindowResized};
+use bevy::{
+    asset::AssetMetaCheck,
+    color::palettes::tailwind,
+    prelude::*,
+    window::{PrimaryWindow, WindowResized},
+};
 use bevy_ecs_tilemap::TilemapPlugin;
 use bevy_light_2d::prelude::*;
 use bevy_prng::WyRand;
 use bevy_rand::plugin::EntropyPlugin;
 use bevy_rapier2d::plugin::RapierPhysicsPlugin;
 
-use crate::characters::player::Player;
+use crate::{
+    characters::player::Player,
+    settings::{CanvasScaling, Settings},
+};
 
 /// Main function
 fn main() -> AppExit {
@@ -80,9 +91,12 @@ impl Plugin for AppPlugin {
             procgen::plugin,
             #[cfg(feature = "dev")]
             dev_tools::plugin,
+            hud::plugin,
             levels::plugin,
             menus::plugin,
+            particles::plugin,
             screens::plugin,
+            settings::plugin,
             theme::plugin,
         ));
 
@@ -100,12 +114,19 @@ impl Plugin for AppPlugin {
         // Set up the `Pause` state.
         app.init_state::<Pause>();
         app.configure_sets(Update, PausableSystems.run_if(in_state(Pause(false))));
+        app.configure_sets(FixedUpdate, PausableSystems.run_if(in_state(Pause(false))));
 
         // Spawn the main camera.
         app.add_systems(Startup, spawn_camera);
 
         // Update the main camera
-        app.add_systems(Update, (fit_canvas, update_camera));
+        app.add_systems(
+            Update,
+            (
+                fit_canvas.run_if(on_message::<WindowResized>.or(resource_changed::<Settings>)),
+                update_camera,
+            ),
+        );
     }
 }
 
@@ -130,6 +151,19 @@ struct Pause(pub(crate) bool);
 #[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
 struct PausableSystems;
 
+/// Run condition that is true if any asset of type `A` has been modified, e.g. by hot-reloading.
+fn asset_modified<A>(mut msgs: MessageReader<AssetEvent<A>>) -> bool
+where
+    A: Asset,
+{
+    // NOTE: Remaining messages are cleared so that they are not read again in the next frame.
+    let modified = msgs
+        .read()
+        .any(|msg| matches!(msg, AssetEvent::Modified { .. }));
+    msgs.clear();
+    modified
+}
+
 /// Camera that renders the world to the canvas.
 #[derive(Component)]
 struct CanvasCamera;
@@ -144,6 +178,7 @@ fn spawn_camera(mut commands: Commands) {
         Camera2d,
         Msaa::Off,
         CanvasCamera,
+        SpatialListener::new(audio::SPATIAL_EAR_GAP),
         Light2d {
             ambient_light: AmbientLight2d {
                 color: AMBIENT_LIGHT_COLOR.into(),
@@ -153,23 +188,29 @@ fn spawn_camera(mut commands: Commands) {
     ));
 }
 
-/// In-game resolution height.
+/// Default in-game resolution height.
 const RES_HEIGHT: f32 = 180.;
 
-/// Scales camera projection to fit the window (integer multiples only).
+/// Scales camera projection to fit the window with the internal resolution from [`Settings`].
 ///
 /// Heavily inspired by: <https://bevy.org/examples/2d-rendering/pixel-grid-snap/>
 fn fit_canvas(
-    mut msgs: MessageReader<WindowResized>,
+    window: Single<&Window, With<PrimaryWindow>>,
     mut projection: Single<&mut Projection, With<CanvasCamera>>,
+    settings: Res<Settings>,
 ) {
     let Projection::Orthographic(projection) = &mut **projection else {
         return;
     };
-    for msg in msgs.read() {
-        let scale_factor = 1. / (msg.height / RES_HEIGHT).round();
-        projection.scale = scale_factor;
-    }
+
+    // Scale by integer multiples only if configured
+    let display = &settings.display;
+    let scale = window.height() / display.resolution;
+    let scale = match display.scaling {
+        CanvasScaling::Integer => scale.round().max(1.),
+        CanvasScaling::Fit => scale,
+    };
+    projection.scale = 1. / scale;
 }
 
 /// How quickly should the camera snap to the target location.
@@ -11,8 +11,12 @@
 
 //! The game's menus and transitions between them.
 
+mod character_select;
+mod controls;
 pub(crate) mod credits;
+mod display;
 mod main;
+mod navigation;
 mod pause;
 mod settings;
 
@@ -24,8 +28,12 @@ pub(super) fn plugin(app: &mut App) {
 
     // Add child plugins
     app.add_plugins((
+        character_select::plugin,
+        controls::plugin,
+        display::plugin,
         credits::plugin,
         main::plugin,
+        navigation::plugin,
         settings::plugin,
         pause::plugin,
     ));
@@ -37,7 +45,10 @@ pub(crate) enum Menu {
     #[default]
     None,
     Main,
+    CharacterSelect,
     Credits,
     Settings,
+    Controls,
+    Display,
     Pause,
 }
@@ -0,0 +1,180 @@
+/*
+ * File: character_select.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! The character select menu (seen on the title screen before gameplay).
+
+use bevy::{
+    ecs::spawn::{SpawnIter, SpawnWith},
+    prelude::*,
+};
+use bevy_asset_loader::prelude::*;
+use bevy_spritesheet_animation::prelude::*;
+
+use crate::{
+    characters::{
+        animations::idle_preview,
+        definition::{CharacterData, CharacterHandle},
+        player::{Player, PlayerDefinitions},
+    },
+    logging::error::ERR_LOADING_CHARACTER_DATA,
+    menus::{Menu, navigation::back_just_pressed},
+    screens::Screen,
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Open character select menu
+    app.add_systems(OnEnter(Menu::CharacterSelect), spawn_character_select_menu);
+
+    // Exit character select menu on pressing Escape
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::CharacterSelect).and(back_just_pressed)),
+    );
+}
+
+/// Size of the idle preview in pixels
+const PREVIEW_SIZE: f32 = 96.;
+
+/// Spawn menu with a card for every player definition
+fn spawn_character_select_menu(
+    mut commands: Commands,
+    mut atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+    mut global_animations: ResMut<Assets<Animation>>,
+    definitions: Res<PlayerDefinitions>,
+    data: Res<Assets<CharacterData<Player>>>,
+    images: Res<Assets<Image>>,
+) {
+    let cards: Vec<_> = definitions
+        .0
+        .iter()
+        .map(|definition| {
+            let data = data
+                .get(definition.handle.id())
+                .expect(ERR_LOADING_CHARACTER_DATA);
+            let preview = definition.image.as_ref().and_then(|image| {
+                idle_preview(
+                    &data.animation,
+                    image,
+                    &images,
+                    &mut atlas_layouts,
+                    &mut global_animations,
+                )
+            });
+            character_card(definition.handle.clone(), data, preview)
+        })
+        .collect();
+
+    commands.spawn((
+        widgets::common::ui_root("Character Select Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::CharacterSelect),
+        children![
+            widgets::common::header("Choose Character"),
+            (
+                Name::new("Character Cards"),
+                Node {
+                    column_gap: px(30),
+                    ..default()
+                },
+                Children::spawn(SpawnIter(cards.into_iter())),
+            ),
+            widgets::common::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+/// Card with name, idle preview, stats and a button to select the character
+fn character_card(
+    handle: Handle<CharacterData<Player>>,
+    data: &CharacterData<Player>,
+    preview: Option<(ImageNode, SpritesheetAnimation)>,
+) -> impl Bundle {
+    let stats = data.stats.as_ref().map_or_else(
+        || {
+            [
+                "Speed: -".to_string(),
+                "Jump: -".to_string(),
+                "Stamina: -".to_string(),
+            ]
+        },
+        |stats| {
+            [
+                format!("Speed: {:.0}", stats.walk_speed),
+                format!("Jump: {:.0}", stats.jump.max_height),
+                format!("Stamina: {:.0}", stats.max_stamina),
+            ]
+        },
+    );
+
+    // Fall back to the file stem if the definition has no name
+    let name = if data.name.is_empty() {
+        handle
+            .path()
+            .and_then(|path| path.path().file_name())
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('.').next())
+            .unwrap_or_default()
+            .to_string()
+    } else {
+        data.name.clone()
+    };
+
+    (
+        Name::new("Character Card"),
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: px(10),
+            ..default()
+        },
+        Children::spawn((
+            Spawn(widgets::common::header(name)),
+            SpawnWith(move |parent: &mut ChildSpawner| {
+                let mut entity = parent.spawn((
+                    Name::new("Preview"),
+                    Node {
+                        width: px(PREVIEW_SIZE),
+                        height: px(PREVIEW_SIZE),
+                        ..default()
+                    },
+                ));
+                if let Some(preview) = preview {
+                    entity.insert(preview);
+                }
+            }),
+            SpawnIter(stats.into_iter().map(widgets::common::label)),
+            Spawn(widgets::common::button(
+                "Select",
+                move |_: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut dynamic_assets: ResMut<DynamicAssets>,
+                      mut next_screen: ResMut<NextState<Screen>>,
+                      data: Res<Assets<CharacterData<Player>>>| {
+                    // Register assets of the chosen definition to load them in `Screen::CharacterLoading`
+                    let data = data.get(handle.id()).expect(ERR_LOADING_CHARACTER_DATA);
+                    data.register(&mut dynamic_assets);
+
+                    commands.insert_resource(CharacterHandle::<Player>(handle.clone()));
+                    next_screen.set(Screen::CharacterLoading);
+                },
+            )),
+        )),
+    )
+}
+
+/// Go back to main menu on click
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
+
+/// Go back to main menu if a menu switch is initialized
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Main);
+}
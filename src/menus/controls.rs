@@ -0,0 +1,323 @@
+/*
+ * File: controls.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! The controls menu.
+//!
+//! Bindings are changed in [`Settings`] and applied when the player is spawned.
+
+use bevy::{diagnostic::FrameCount, ecs::spawn::SpawnWith, prelude::*};
+
+use crate::{
+    menus::{Menu, navigation::back_just_pressed},
+    settings::{
+        Settings,
+        bindings::{InputBinding, InputBindings, PlayerAction},
+    },
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Open controls menu on state and stop capturing on exit
+    app.init_resource::<Capture>();
+    app.add_systems(OnEnter(Menu::Controls), spawn_controls_menu);
+    app.add_systems(OnExit(Menu::Controls), stop_capture);
+
+    // Exit controls menu on pressing Escape if not capturing, capture bindings and update labels
+    app.add_systems(
+        Update,
+        (
+            go_back.run_if(back_just_pressed.and(not(capturing))),
+            capture_binding.run_if(capturing),
+            (update_binding_labels, update_status_label),
+        )
+            .chain()
+            .run_if(in_state(Menu::Controls)),
+    );
+}
+
+/// Action that the next pressed input is bound to and a message for the user
+#[derive(Resource, Default, Debug)]
+pub(super) struct Capture {
+    action: Option<PlayerAction>,
+    /// Frame that capturing has been started in
+    started: u32,
+    message: String,
+}
+impl Capture {
+    /// Start capturing an input for `action` in the current `frame`
+    fn start(&mut self, action: PlayerAction, frame: &FrameCount) {
+        self.action = Some(action);
+        self.started = frame.0;
+        self.message = format!(
+            "Press a key or button for {}. Escape cancels.",
+            action.name()
+        );
+    }
+}
+
+/// Label that displays the bindings of a [`PlayerAction`]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct BindingsLabel(PlayerAction);
+
+/// Label that displays [`Capture::message`]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct StatusLabel;
+
+/// Spawn controls menu
+fn spawn_controls_menu(mut commands: Commands) {
+    commands.spawn((
+        widgets::common::ui_root("Controls Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Controls),
+        children![
+            widgets::common::header("Controls"),
+            grid(),
+            (widgets::common::label(""), StatusLabel),
+            widgets::common::button("Reset to Defaults", reset_bindings),
+            widgets::common::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+/// Grid with one row per [`PlayerAction`]
+fn grid() -> impl Bundle {
+    (
+        Name::new("Controls Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(10),
+            column_gap: px(30),
+            grid_template_columns: RepeatedGridTrack::px(2, 400.0),
+            ..default()
+        },
+        Children::spawn(SpawnWith(|parent: &mut ChildSpawner| {
+            for action in PlayerAction::ALL {
+                parent.spawn((
+                    widgets::common::label(action.name()),
+                    Node {
+                        justify_self: JustifySelf::End,
+                        ..default()
+                    },
+                ));
+                parent.spawn(binding_widget(action));
+            }
+        })),
+    )
+}
+
+/// Widget to display, capture and clear bindings of a [`PlayerAction`]
+fn binding_widget(action: PlayerAction) -> impl Bundle {
+    (
+        Name::new("Binding Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            column_gap: px(10),
+            ..default()
+        },
+        children![
+            widgets::common::button_small(
+                "+",
+                move |_: On<Pointer<Click>>,
+                      mut capture: ResMut<Capture>,
+                      frame: Res<FrameCount>| {
+                    capture.start(action, &frame);
+                },
+            ),
+            widgets::common::button_small(
+                "x",
+                move |_: On<Pointer<Click>>, mut settings: ResMut<Settings>| {
+                    settings.bindings.get_mut(action).clear();
+                },
+            ),
+            (
+                Name::new("Current Bindings"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    ..default()
+                },
+                children![(widgets::common::label(""), BindingsLabel(action))],
+            ),
+        ],
+    )
+}
+
+/// Whether an input is being captured
+pub(super) fn capturing(capture: Res<Capture>) -> bool {
+    capture.action.is_some()
+}
+
+/// Stop capturing and clear message
+fn stop_capture(mut capture: ResMut<Capture>) {
+    *capture = Capture::default();
+}
+
+/// Bind the next pressed key or gamepad button to the captured [`PlayerAction`]
+///
+/// Inputs in the frame that capturing has been started in are ignored.
+pub(super) fn capture_binding(
+    gamepads: Query<&Gamepad>,
+    mut capture: ResMut<Capture>,
+    mut settings: ResMut<Settings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    frame: Res<FrameCount>,
+) {
+    let Some(action) = capture.action else {
+        return;
+    };
+
+    // Return if capturing has been started in this frame
+    // NOTE: Pressing a focused button with Enter or gamepad South starts capturing in the same frame,
+    //       so that input would otherwise be captured immediately.
+    if capture.started == frame.0 {
+        return;
+    }
+
+    // Cancel on pressing Escape
+    // NOTE: Gamepad East is not used to cancel so that it can be bound.
+    if keys.just_pressed(KeyCode::Escape) {
+        *capture = Capture::default();
+        return;
+    }
+
+    // Return if nothing has been pressed
+    let binding = keys
+        .get_just_pressed()
+        .next()
+        .map(|key| InputBinding::Key(*key))
+        .or_else(|| {
+            gamepads.iter().find_map(|gamepad| {
+                gamepad
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| InputBinding::Gamepad(*button))
+            })
+        });
+    let Some(binding) = binding else {
+        return;
+    };
+
+    // Bind and report conflicts
+    capture.action = None;
+    capture.message = match settings.bindings.bind(action, binding) {
+        Some(other) => format!("{} was unbound from {}.", binding.name(), other.name()),
+        None => String::new(),
+    };
+}
+
+/// Reset bindings to defaults
+fn reset_bindings(
+    _: On<Pointer<Click>>,
+    mut capture: ResMut<Capture>,
+    mut settings: ResMut<Settings>,
+) {
+    settings.bindings = InputBindings::default();
+    *capture = Capture::default();
+}
+
+/// Update labels that display bindings
+fn update_binding_labels(
+    mut query: Query<(&mut Text, &BindingsLabel)>,
+    capture: Res<Capture>,
+    settings: Res<Settings>,
+) {
+    for (mut label, action) in &mut query {
+        let bindings = settings.bindings.get(action.0);
+        label.0 = if capture.action == Some(action.0) {
+            "...".to_string()
+        } else if bindings.is_empty() {
+            "None".to_string()
+        } else {
+            bindings
+                .iter()
+                .map(|binding| binding.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+    }
+}
+
+/// Update label that displays [`Capture::message`]
+fn update_status_label(mut label: Single<&mut Text, With<StatusLabel>>, capture: Res<Capture>) {
+    if label.0 != capture.message {
+        label.0.clone_from(&capture.message);
+    }
+}
+
+/// Go back on pointer click
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
+
+/// Go back manually
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+
+    /// World with settings and a capture for `action` that has been started by pressing `key`
+    fn world(action: PlayerAction, key: KeyCode) -> World {
+        let mut world = World::new();
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(key);
+        let mut capture = Capture::default();
+        capture.start(action, &FrameCount(0));
+
+        world.insert_resource(keys);
+        world.insert_resource(capture);
+        world.insert_resource(FrameCount(0));
+        world.insert_resource(Settings::default());
+        world
+    }
+
+    #[test]
+    fn confirm_input_is_not_captured() {
+        let mut world = world(PlayerAction::Jump, KeyCode::Enter);
+
+        // Run capture in the frame that it has been started in
+        world.run_system_once(capture_binding).unwrap();
+
+        let binding = InputBinding::Key(KeyCode::Enter);
+        assert!(
+            !world
+                .resource::<Settings>()
+                .bindings
+                .get(PlayerAction::Jump)
+                .contains(&binding)
+        );
+        assert_eq!(world.resource::<Capture>().action, Some(PlayerAction::Jump));
+    }
+
+    #[test]
+    fn next_input_is_captured() {
+        let mut world = world(PlayerAction::Jump, KeyCode::Enter);
+        world.run_system_once(capture_binding).unwrap();
+
+        // Press another key in the next frame
+        world.resource_mut::<FrameCount>().0 += 1;
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        keys.clear();
+        keys.press(KeyCode::KeyJ);
+        world.run_system_once(capture_binding).unwrap();
+
+        let bindings = world
+            .resource::<Settings>()
+            .bindings
+            .get(PlayerAction::Jump);
+        assert!(bindings.contains(&InputBinding::Key(KeyCode::KeyJ)));
+        assert!(!bindings.contains(&InputBinding::Key(KeyCode::Enter)));
+        assert_eq!(world.resource::<Capture>().action, None);
+    }
+}
@@ -11,10 +11,14 @@
 
 //! The credits menu.
 
-use bevy::{ecs::spawn::SpawnIter, input::common_conditions::input_just_pressed, prelude::*};
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
 use bevy_asset_loader::prelude::*;
 
-use crate::{audio::music, menus::Menu, theme::prelude::*};
+use crate::{
+    audio::music,
+    menus::{Menu, navigation::back_just_pressed},
+    theme::prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
     // Open credits menu
@@ -23,7 +27,7 @@ pub(super) fn plugin(app: &mut App) {
     // Exit credits menu on pressing Escape
     app.add_systems(
         Update,
-        go_back.run_if(in_state(Menu::Credits).and(input_just_pressed(KeyCode::Escape))),
+        go_back.run_if(in_state(Menu::Credits).and(back_just_pressed)),
     );
     // Start music for credits menu
     app.add_systems(OnEnter(Menu::Credits), start_credits_music);
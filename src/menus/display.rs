@@ -0,0 +1,191 @@
+/*
+ * File: display.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! The display menu.
+//!
+//! Options are changed in [`Settings`] and applied to the window and canvas immediately.
+
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
+
+use crate::{
+    menus::{Menu, navigation::back_just_pressed},
+    settings::{RESOLUTIONS, Settings},
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Open display menu on state
+    app.add_systems(OnEnter(Menu::Display), spawn_display_menu);
+
+    // Exit display menu on pressing Escape
+    app.add_systems(
+        Update,
+        go_back.run_if(in_state(Menu::Display).and(back_just_pressed)),
+    );
+
+    // Update labels of display options
+    app.add_systems(
+        Update,
+        update_display_labels.run_if(in_state(Menu::Display)),
+    );
+}
+
+/// Option in the display menu
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+enum DisplayOption {
+    WindowMode,
+    Vsync,
+    Scaling,
+    Resolution,
+}
+impl DisplayOption {
+    /// All options in the order they are displayed
+    const ALL: [Self; 4] = [
+        Self::WindowMode,
+        Self::Vsync,
+        Self::Scaling,
+        Self::Resolution,
+    ];
+
+    /// Name that is displayed in the display menu
+    fn name(self) -> &'static str {
+        match self {
+            Self::WindowMode => "Window Mode",
+            Self::Vsync => "VSync",
+            Self::Scaling => "Pixel Scaling",
+            Self::Resolution => "Resolution",
+        }
+    }
+
+    /// Current value of this option in `settings`
+    fn value(self, settings: &Settings) -> String {
+        let display = &settings.display;
+        match self {
+            Self::WindowMode => display.window_mode.name().to_string(),
+            Self::Vsync => if display.vsync { "On" } else { "Off" }.to_string(),
+            Self::Scaling => display.scaling.name().to_string(),
+            Self::Resolution => format!("{}p", display.resolution),
+        }
+    }
+
+    /// Change this option in `settings` to the next value
+    fn cycle(self, settings: &mut Settings) {
+        let display = &mut settings.display;
+        match self {
+            Self::WindowMode => display.window_mode = display.window_mode.cycled(),
+            Self::Vsync => display.vsync = !display.vsync,
+            Self::Scaling => display.scaling = display.scaling.toggled(),
+            Self::Resolution => {
+                // NOTE: Unknown resolutions from a modified settings file start at the first one.
+                let index = RESOLUTIONS
+                    .iter()
+                    .position(|resolution| *resolution == display.resolution)
+                    .map_or(0, |i| (i + 1) % RESOLUTIONS.len());
+                display.resolution = RESOLUTIONS[index];
+            }
+        }
+    }
+}
+
+/// Label that displays the value of a [`DisplayOption`]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct DisplayOptionLabel(DisplayOption);
+
+/// Spawn display menu
+fn spawn_display_menu(mut commands: Commands) {
+    commands.spawn((
+        widgets::common::ui_root("Display Menu"),
+        GlobalZIndex(2),
+        DespawnOnExit(Menu::Display),
+        children![
+            widgets::common::header("Display"),
+            grid(),
+            widgets::common::button("Back", go_back_on_click),
+        ],
+    ));
+}
+
+/// Grid with one row per [`DisplayOption`]
+fn grid() -> impl Bundle {
+    (
+        Name::new("Display Grid"),
+        Node {
+            display: Display::Grid,
+            row_gap: px(10),
+            column_gap: px(30),
+            grid_template_columns: RepeatedGridTrack::px(2, 400.0),
+            ..default()
+        },
+        Children::spawn(SpawnWith(|parent: &mut ChildSpawner| {
+            for option in DisplayOption::ALL {
+                parent.spawn((
+                    widgets::common::label(option.name()),
+                    Node {
+                        justify_self: JustifySelf::End,
+                        ..default()
+                    },
+                ));
+                parent.spawn(option_widget(option));
+            }
+        })),
+    )
+}
+
+/// Widget to display and change a [`DisplayOption`]
+fn option_widget(option: DisplayOption) -> impl Bundle {
+    (
+        Name::new("Display Option Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            column_gap: px(10),
+            ..default()
+        },
+        children![
+            (
+                Name::new("Current Value"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widgets::common::label(""), DisplayOptionLabel(option))],
+            ),
+            widgets::common::button_small(
+                ">",
+                move |_: On<Pointer<Click>>, mut settings: ResMut<Settings>| {
+                    option.cycle(&mut settings);
+                },
+            ),
+        ],
+    )
+}
+
+/// Update labels that display values of [`DisplayOption`]s
+fn update_display_labels(
+    mut query: Query<(&mut Text, &DisplayOptionLabel)>,
+    settings: Res<Settings>,
+) {
+    for (mut label, option) in &mut query {
+        let value = option.0.value(&settings);
+        if label.0 != value {
+            label.0 = value;
+        }
+    }
+}
+
+/// Go back on pointer click
+fn go_back_on_click(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
+
+/// Go back manually
+fn go_back(mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Settings);
+}
@@ -13,7 +13,7 @@
 
 use bevy::prelude::*;
 
-use crate::{menus::Menu, screens::Screen, theme::widgets};
+use crate::{menus::Menu, theme::widgets};
 
 pub(super) fn plugin(app: &mut App) {
     // Open main menu
@@ -28,7 +28,7 @@ fn spawn_main_menu(mut commands: Commands) {
         DespawnOnExit(Menu::Main),
         #[cfg(not(target_family = "wasm"))]
         children![
-            widgets::common::button("Play", enter_gameplay_screen),
+            widgets::common::button("Play", open_character_select_menu),
             widgets::common::button("Settings", open_settings_menu),
             widgets::common::button("Credits", open_credits_menu),
             widgets::common::button("Exit", exit_app),
@@ -36,16 +36,16 @@ fn spawn_main_menu(mut commands: Commands) {
         // Do not add exit button for wasm
         #[cfg(target_family = "wasm")]
         children![
-            widgets::common::button("Play", enter_gameplay_screen),
+            widgets::common::button("Play", open_character_select_menu),
             widgets::common::button("Settings", open_settings_menu),
             widgets::common::button("Credits", open_credits_menu),
         ],
     ));
 }
 
-/// Enter the gameplay screen
-fn enter_gameplay_screen(_: On<Pointer<Click>>, mut next_screen: ResMut<NextState<Screen>>) {
-    next_screen.set(Screen::Gameplay);
+/// Open the character select menu
+fn open_character_select_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::CharacterSelect);
 }
 
 /// Open settings
@@ -0,0 +1,287 @@
+/*
+ * File: navigation.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Keyboard and gamepad navigation of menus.
+//!
+//! Buttons with [`InteractionPalette`] can be focused with arrow keys, D-pad or left stick and
+//! pressed with Enter or South. Back is mapped to Escape or East via [`back_just_pressed`].
+
+use std::time::Duration;
+
+use bevy::{
+    camera::NormalizedRenderTarget,
+    input::mouse::AccumulatedMouseMotion,
+    input_focus::{InputFocus, InputFocusVisible},
+    picking::{
+        backend::HitData,
+        events::{Click, Pointer},
+        pointer::{Location, PointerButton, PointerId},
+    },
+    platform::collections::HashMap,
+    prelude::*,
+    ui::UiGlobalTransform,
+    window::{PrimaryWindow, WindowRef},
+};
+
+use crate::{
+    menus::{
+        Menu,
+        controls::{capture_binding, capturing},
+    },
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Remember focus for each menu
+    app.init_resource::<FocusMemory>();
+
+    // Navigate menus if not capturing bindings
+    // NOTE: This runs before capturing so that the input that completes a capture does not also press
+    //       a button. The input that starts a capture is ignored by `capture_binding` instead.
+    app.add_systems(
+        Update,
+        (
+            restore_focus,
+            navigate_focus,
+            press_focused,
+            remember_focus,
+            hide_focus_on_mouse,
+        )
+            .chain()
+            .before(capture_binding)
+            .run_if(not(in_state(Menu::None)).and(not(capturing))),
+    );
+}
+
+/// Left stick deflection that counts as a direction
+const STICK_THRESHOLD: f32 = 0.5;
+/// Weight of the distance perpendicular to the navigation direction
+///
+/// Higher values prefer buttons that are in line with the focused button.
+const PERPENDICULAR_WEIGHT: f32 = 2.;
+
+/// Keys, gamepad buttons and directions in UI space for navigation
+const DIRECTIONS: [(KeyCode, GamepadButton, Vec2); 4] = [
+    (KeyCode::ArrowUp, GamepadButton::DPadUp, Vec2::NEG_Y),
+    (KeyCode::ArrowDown, GamepadButton::DPadDown, Vec2::Y),
+    (KeyCode::ArrowLeft, GamepadButton::DPadLeft, Vec2::NEG_X),
+    (KeyCode::ArrowRight, GamepadButton::DPadRight, Vec2::X),
+];
+
+/// Position of the last focused button for each [`Menu`]
+#[derive(Resource, Default, Debug)]
+struct FocusMemory(HashMap<Menu, Vec2>);
+
+/// Buttons that can be focused
+type FocusableQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static ComputedNode,
+        &'static UiGlobalTransform,
+        &'static InheritedVisibility,
+    ),
+    With<InteractionPalette>,
+>;
+
+/// Visible buttons that have been laid out with their center in UI space
+fn focusable(query: &FocusableQuery) -> Vec<(Entity, Vec2)> {
+    query
+        .iter()
+        .filter(|(_, node, _, visibility)| visibility.get() && node.size() != Vec2::ZERO)
+        .map(|(entity, _, transform, _)| (entity, transform.translation))
+        .collect()
+}
+
+/// Run condition that is true if Escape or East has just been pressed
+pub(crate) fn back_just_pressed(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+) -> bool {
+    keys.just_pressed(KeyCode::Escape)
+        || gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::East))
+}
+
+/// Focus the remembered or first button if nothing valid is focused
+fn restore_focus(
+    query: FocusableQuery,
+    mut focus: ResMut<InputFocus>,
+    memory: Res<FocusMemory>,
+    menu: Res<State<Menu>>,
+) {
+    // Return if a button is focused
+    let buttons = focusable(&query);
+    if focus
+        .get()
+        .is_some_and(|entity| buttons.iter().any(|(button, _)| *button == entity))
+    {
+        return;
+    }
+
+    // Focus nearest button to the remembered position or the top left button
+    let button = match memory.0.get(menu.get()) {
+        Some(pos) => buttons
+            .iter()
+            .min_by(|(_, a), (_, b)| a.distance(*pos).total_cmp(&b.distance(*pos))),
+        None => buttons
+            .iter()
+            .min_by(|(_, a), (_, b)| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))),
+    };
+    let entity = button.map(|(entity, _)| *entity);
+    if focus.get() != entity {
+        focus.0 = entity;
+    }
+}
+
+/// Move focus in the direction of pressed arrow keys, D-pad or left stick
+fn navigate_focus(
+    query: FocusableQuery,
+    gamepads: Query<&Gamepad>,
+    mut last_stick: Local<Option<Vec2>>,
+    mut focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    let mut direction = DIRECTIONS
+        .into_iter()
+        .find(|(key, button, _)| {
+            keys.just_pressed(*key) || gamepads.iter().any(|gamepad| gamepad.just_pressed(*button))
+        })
+        .map(|(_, _, direction)| direction);
+
+    // Only navigate with left stick if its direction changes
+    // NOTE: Stick y points up while UI y points down.
+    let stick = gamepads
+        .iter()
+        .map(|gamepad| gamepad.left_stick())
+        .find(|stick| stick.length() > STICK_THRESHOLD)
+        .map(|stick| {
+            if stick.x.abs() > stick.y.abs() {
+                Vec2::new(stick.x.signum(), 0.)
+            } else {
+                Vec2::new(0., -stick.y.signum())
+            }
+        });
+    if stick != *last_stick {
+        *last_stick = stick;
+        direction = direction.or(stick);
+    }
+
+    // Return if there is nothing to navigate
+    let Some(direction) = direction else {
+        return;
+    };
+    if !focus_visible.0 {
+        focus_visible.0 = true;
+    }
+    let buttons = focusable(&query);
+    let Some(current) = focus
+        .get()
+        .and_then(|entity| buttons.iter().find(|(button, _)| *button == entity))
+        .map(|(_, pos)| *pos)
+    else {
+        return;
+    };
+
+    // Focus the closest button in direction
+    let next = buttons
+        .iter()
+        .filter_map(|(entity, pos)| {
+            let offset = *pos - current;
+            let along = offset.dot(direction);
+            if along <= 0. {
+                return None;
+            }
+            let across = offset.perp_dot(direction).abs();
+            Some((*entity, along + across * PERPENDICULAR_WEIGHT))
+        })
+        .min_by(|(_, a), (_, b)| a.total_cmp(b));
+    if let Some((entity, _)) = next {
+        focus.set(entity);
+    }
+}
+
+/// Press the focused button on Enter or South
+///
+/// This triggers [`Pointer<Click>`] so that buttons behave the same as with a mouse.
+fn press_focused(
+    query: Query<&UiGlobalTransform, With<InteractionPalette>>,
+    gamepads: Query<&Gamepad>,
+    window: Single<Entity, With<PrimaryWindow>>,
+    mut commands: Commands,
+    mut focus_visible: ResMut<InputFocusVisible>,
+    focus: Res<InputFocus>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    // Return if confirm has not been pressed
+    if !keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter])
+        && !gamepads
+            .iter()
+            .any(|gamepad| gamepad.just_pressed(GamepadButton::South))
+    {
+        return;
+    }
+    if !focus_visible.0 {
+        focus_visible.0 = true;
+    }
+
+    // Get focused button
+    let Some(entity) = focus.get() else {
+        return;
+    };
+    let Ok(transform) = query.get(entity) else {
+        return;
+    };
+    let Some(target) = WindowRef::Primary.normalize(Some(*window)) else {
+        return;
+    };
+
+    commands.trigger(Pointer::new(
+        PointerId::Mouse,
+        Location {
+            target: NormalizedRenderTarget::Window(target),
+            position: transform.translation,
+        },
+        Click {
+            button: PointerButton::Primary,
+            hit: HitData::new(Entity::PLACEHOLDER, 0., None, None),
+            duration: Duration::ZERO,
+        },
+        entity,
+    ));
+}
+
+/// Remember position of the focused button for the current [`Menu`]
+fn remember_focus(
+    query: Query<&UiGlobalTransform, With<InteractionPalette>>,
+    mut memory: ResMut<FocusMemory>,
+    focus: Res<InputFocus>,
+    menu: Res<State<Menu>>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    let Some(transform) = focus.get().and_then(|entity| query.get(entity).ok()) else {
+        return;
+    };
+    memory.0.insert(*menu.get(), transform.translation);
+}
+
+/// Hide focus if the mouse is used
+fn hide_focus_on_mouse(
+    mut focus_visible: ResMut<InputFocusVisible>,
+    motion: Res<AccumulatedMouseMotion>,
+) {
+    if motion.delta != Vec2::ZERO && focus_visible.0 {
+        focus_visible.0 = false;
+    }
+}
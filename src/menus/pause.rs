@@ -11,9 +11,13 @@
 
 //! The pause menu.
 
-use bevy::{input::common_conditions::input_just_pressed, prelude::*};
+use bevy::prelude::*;
 
-use crate::{menus::Menu, screens::Screen, theme::widgets};
+use crate::{
+    menus::{Menu, navigation::back_just_pressed},
+    screens::Screen,
+    theme::widgets,
+};
 
 pub(super) fn plugin(app: &mut App) {
     // Open pause menu
@@ -22,7 +26,7 @@ pub(super) fn plugin(app: &mut App) {
     // Exit pause menu on pressing Escape
     app.add_systems(
         Update,
-        go_back.run_if(in_state(Menu::Pause).and(input_just_pressed(KeyCode::Escape))),
+        go_back.run_if(in_state(Menu::Pause).and(back_just_pressed)),
     );
 }
 
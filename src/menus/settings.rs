@@ -13,9 +13,15 @@
 //!
 //! Additional settings and accessibility options should go here.
 
-use bevy::{audio::Volume, input::common_conditions::input_just_pressed, prelude::*};
+use bevy::{ecs::spawn::SpawnWith, prelude::*};
 
-use crate::{menus::Menu, screens::Screen, theme::prelude::*};
+use crate::{
+    audio::AudioBus,
+    menus::{Menu, navigation::back_just_pressed},
+    screens::Screen,
+    settings::Settings,
+    theme::prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
     // Open settings menu on state
@@ -24,12 +30,18 @@ pub(super) fn plugin(app: &mut App) {
     // Exit settings menu on pressing Escape
     app.add_systems(
         Update,
-        go_back.run_if(in_state(Menu::Settings).and(input_just_pressed(KeyCode::Escape))),
+        go_back.run_if(in_state(Menu::Settings).and(back_just_pressed)),
     );
-    // Handle changes to global volume from settings menu
+    // Handle changes to global and bus volume, music mode and reduce motion from settings menu
     app.add_systems(
         Update,
-        update_global_volume_label.run_if(in_state(Menu::Settings)),
+        (
+            update_global_volume_label,
+            update_bus_volume_labels,
+            update_music_mode_label,
+            update_reduce_motion_label,
+        )
+            .run_if(in_state(Menu::Settings)),
     );
 }
 
@@ -38,6 +50,21 @@ pub(super) fn plugin(app: &mut App) {
 #[reflect(Component)]
 struct GlobalVolumeLabel;
 
+/// Label that displays the volume of an [`AudioBus`]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct BusVolumeLabel(AudioBus);
+
+/// Label that displays the current music mode
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct MusicModeLabel;
+
+/// Label that displays whether motion is reduced
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct ReduceMotionLabel;
+
 /// Spawn settings menu
 fn spawn_settings_menu(mut commands: Commands) {
     commands.spawn((
@@ -47,6 +74,8 @@ fn spawn_settings_menu(mut commands: Commands) {
         children![
             widgets::common::header("Settings"),
             grid(),
+            widgets::common::button("Display", open_display_menu),
+            widgets::common::button("Controls", open_controls_menu),
             widgets::common::button("Back", go_back_on_click),
         ],
     ));
@@ -63,16 +92,45 @@ fn grid() -> impl Bundle {
             grid_template_columns: RepeatedGridTrack::px(2, 400.0),
             ..default()
         },
-        children![
-            (
+        Children::spawn((
+            Spawn((
                 widgets::common::label("Master Volume"),
                 Node {
                     justify_self: JustifySelf::End,
                     ..default()
+                },
+            )),
+            Spawn(global_volume_widget()),
+            // One row per audio bus
+            SpawnWith(|parent: &mut ChildSpawner| {
+                for bus in AudioBus::ALL {
+                    parent.spawn((
+                        widgets::common::label(bus.name()),
+                        Node {
+                            justify_self: JustifySelf::End,
+                            ..default()
+                        },
+                    ));
+                    parent.spawn(bus_volume_widget(bus));
                 }
-            ),
-            global_volume_widget(),
-        ],
+            }),
+            Spawn((
+                widgets::common::label("Music Mode"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                },
+            )),
+            Spawn(music_mode_widget()),
+            Spawn((
+                widgets::common::label("Reduce Motion"),
+                Node {
+                    justify_self: JustifySelf::End,
+                    ..default()
+                },
+            )),
+            Spawn(reduce_motion_widget()),
+        )),
     )
 }
 
@@ -100,32 +158,182 @@ fn global_volume_widget() -> impl Bundle {
     )
 }
 
-/// Minimum global volume
+/// Widget to adjust volume of an [`AudioBus`] and mute it
+fn bus_volume_widget(bus: AudioBus) -> impl Bundle {
+    (
+        Name::new("Bus Volume Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            column_gap: px(10),
+            ..default()
+        },
+        children![
+            widgets::common::button_small(
+                "-",
+                move |_: On<Pointer<Click>>, mut settings: ResMut<Settings>| {
+                    let volume = &mut settings.audio.buses.get_mut(bus).volume;
+                    *volume = (*volume - 0.1).max(MIN_VOLUME);
+                },
+            ),
+            (
+                Name::new("Current Volume"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widgets::common::label(""), BusVolumeLabel(bus))],
+            ),
+            widgets::common::button_small(
+                "+",
+                move |_: On<Pointer<Click>>, mut settings: ResMut<Settings>| {
+                    let volume = &mut settings.audio.buses.get_mut(bus).volume;
+                    *volume = (*volume + 0.1).min(MAX_BUS_VOLUME);
+                },
+            ),
+            widgets::common::button_small(
+                "M",
+                move |_: On<Pointer<Click>>, mut settings: ResMut<Settings>| {
+                    let muted = &mut settings.audio.buses.get_mut(bus).muted;
+                    *muted = !*muted;
+                },
+            ),
+        ],
+    )
+}
+
+/// Widget to toggle the music mode
+fn music_mode_widget() -> impl Bundle {
+    (
+        Name::new("Music Mode Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            column_gap: px(10),
+            ..default()
+        },
+        children![
+            (
+                Name::new("Current Music Mode"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widgets::common::label(""), MusicModeLabel)],
+            ),
+            widgets::common::button_small(">", toggle_music_mode),
+        ],
+    )
+}
+
+/// Widget to toggle reduce motion
+fn reduce_motion_widget() -> impl Bundle {
+    (
+        Name::new("Reduce Motion Widget"),
+        Node {
+            justify_self: JustifySelf::Start,
+            column_gap: px(10),
+            ..default()
+        },
+        children![
+            (
+                Name::new("Current Reduce Motion"),
+                Node {
+                    padding: UiRect::horizontal(px(10)),
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                children![(widgets::common::label(""), ReduceMotionLabel)],
+            ),
+            widgets::common::button_small(">", toggle_reduce_motion),
+        ],
+    )
+}
+
+/// Minimum global and bus volume
 const MIN_VOLUME: f32 = 0.0;
 /// Maximum global volume
 const MAX_VOLUME: f32 = 3.0;
+/// Maximum bus volume
+const MAX_BUS_VOLUME: f32 = 1.0;
 
 /// Lower global volume
-fn lower_global_volume(_: On<Pointer<Click>>, mut global_volume: ResMut<GlobalVolume>) {
-    let linear = (global_volume.volume.to_linear() - 0.1).max(MIN_VOLUME);
-    global_volume.volume = Volume::Linear(linear);
+fn lower_global_volume(_: On<Pointer<Click>>, mut settings: ResMut<Settings>) {
+    settings.audio.master = (settings.audio.master - 0.1).max(MIN_VOLUME);
 }
 
 /// Raise global volume
-fn raise_global_volume(_: On<Pointer<Click>>, mut global_volume: ResMut<GlobalVolume>) {
-    let linear = (global_volume.volume.to_linear() + 0.1).min(MAX_VOLUME);
-    global_volume.volume = Volume::Linear(linear);
+fn raise_global_volume(_: On<Pointer<Click>>, mut settings: ResMut<Settings>) {
+    settings.audio.master = (settings.audio.master + 0.1).min(MAX_VOLUME);
 }
 
 /// Update global volume label that displays volume
 fn update_global_volume_label(
     mut label: Single<&mut Text, With<GlobalVolumeLabel>>,
-    global_volume: Res<GlobalVolume>,
+    settings: Res<Settings>,
 ) {
-    let percent = 100.0 * global_volume.volume.to_linear();
+    let percent = 100.0 * settings.audio.master;
     label.0 = format!("{percent:3.0}%");
 }
 
+/// Update bus volume labels that display volume or whether the bus is muted
+fn update_bus_volume_labels(
+    mut query: Query<(&mut Text, &BusVolumeLabel)>,
+    settings: Res<Settings>,
+) {
+    for (mut label, bus) in &mut query {
+        let bus = settings.audio.buses.get(bus.0);
+        label.0 = if bus.muted {
+            "Muted".to_string()
+        } else {
+            let percent = 100.0 * bus.volume;
+            format!("{percent:3.0}%")
+        };
+    }
+}
+
+/// Toggle music mode
+fn toggle_music_mode(_: On<Pointer<Click>>, mut settings: ResMut<Settings>) {
+    settings.audio.music_mode = settings.audio.music_mode.toggled();
+}
+
+/// Update music mode label that displays the current music mode
+fn update_music_mode_label(
+    mut label: Single<&mut Text, With<MusicModeLabel>>,
+    settings: Res<Settings>,
+) {
+    label.0 = settings.audio.music_mode.name().to_string();
+}
+
+/// Toggle reduce motion
+fn toggle_reduce_motion(_: On<Pointer<Click>>, mut settings: ResMut<Settings>) {
+    let reduce_motion = &mut settings.accessibility.reduce_motion;
+    *reduce_motion = !*reduce_motion;
+}
+
+/// Update reduce motion label that displays whether motion is reduced
+fn update_reduce_motion_label(
+    mut label: Single<&mut Text, With<ReduceMotionLabel>>,
+    settings: Res<Settings>,
+) {
+    label.0 = if settings.accessibility.reduce_motion {
+        "On"
+    } else {
+        "Off"
+    }
+    .to_string();
+}
+
+/// Open display menu on pointer click
+fn open_display_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Display);
+}
+
+/// Open controls menu on pointer click
+fn open_controls_menu(_: On<Pointer<Click>>, mut next_menu: ResMut<NextState<Menu>>) {
+    next_menu.set(Menu::Controls);
+}
+
 /// Go back on pointer click
 fn go_back_on_click(
     _: On<Pointer<Click>>,
@@ -0,0 +1,421 @@
+/*
+ * File: particles.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Lightweight CPU particles for dust, goo splashes and hit sparks.
+//!
+//! Particles are pooled sprites that are sorted with [`YSort`]. Emitters are defined in a ron file
+//! and are triggered by writing [`SpawnParticles`] or continuously with [`ParticleEmitter`].
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_common_assets::ron::RonAssetPlugin;
+use bevy_prng::WyRand;
+use bevy_rand::{global::GlobalRng, traits::ForkableSeed as _};
+use iyes_progress::prelude::*;
+use rand::Rng as _;
+
+use crate::{
+    AppSystems, PausableSystems,
+    characters::{
+        Character, CollisionData, CollisionHandle,
+        animations::{AnimationEvent, AnimationEventKind},
+    },
+    levels::{DEFAULT_Z, YSort},
+    logging::{
+        error::{ERR_LOADING_COLLISION_DATA, ERR_LOADING_PARTICLE_DATA},
+        warn::WARN_MISSING_PARTICLE_EMITTER,
+    },
+    screens::Screen,
+    settings::Settings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Add ron asset plugin and message for particles
+    app.add_plugins(RonAssetPlugin::<ParticleData>::new(&["particles.ron"]));
+    app.add_message::<SpawnParticles>();
+
+    // Setup rng
+    app.add_systems(Startup, setup_rng);
+
+    // Load particle data during loading screen
+    app.add_systems(OnEnter(Screen::Loading), setup_particle_data);
+    app.add_systems(
+        Update,
+        track_particle_data
+            .track_progress::<Screen>()
+            .run_if(in_state(Screen::Loading)),
+    );
+
+    // Spawn particle pool
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_particle_pool);
+
+    // Tick particle timers
+    app.add_systems(
+        Update,
+        tick_particle_timers
+            .in_set(AppSystems::TickTimers)
+            .in_set(PausableSystems),
+    );
+
+    // Emit and update particles
+    app.add_systems(
+        Update,
+        (emit_particles, update_particles)
+            .chain()
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+}
+
+/// Path of the ron file for [`ParticleData`]
+const PARTICLE_DATA_PATH: &str = "data/effects/default.particles.ron";
+
+/// Number of particles in the pool
+const PARTICLE_POOL_SIZE: usize = 256;
+
+/// Particle effects that can be triggered
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub(crate) enum ParticleEffect {
+    Footstep,
+    Dust,
+    DashTrail,
+    GooSplash,
+    HitSparks,
+}
+
+/// Particle data deserialized from a ron file
+#[derive(serde::Deserialize, Asset, TypePath, Debug)]
+pub(crate) struct ParticleData {
+    emitters: HashMap<ParticleEffect, EmitterData>,
+}
+
+/// Emitter data deserialized from a ron file
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub(crate) struct EmitterData {
+    /// Number of particles that are spawned at once on [`SpawnParticles`]
+    #[serde(default)]
+    burst: u32,
+    /// Particles per second for [`ParticleEmitter`]
+    #[serde(default)]
+    rate: f32,
+    /// Minimum and maximum lifetime in seconds
+    lifetime_secs: (f32, f32),
+    /// Minimum and maximum initial speed in pixels per second
+    speed: (f32, f32),
+    /// Center of the velocity cone in degrees where `0` points right and `90` points up
+    #[serde(default)]
+    direction_deg: f32,
+    /// Width of the velocity cone in degrees
+    #[serde(default)]
+    spread_deg: f32,
+    /// Acceleration in pixels per second squared
+    #[serde(default)]
+    gravity: Vec2,
+    /// Size of a particle in pixels
+    size: f32,
+    /// Colors over the lifetime that are evenly spaced and interpolated
+    colors: Vec<Srgba>,
+    /// Offset of spawned particles from the trigger position
+    #[serde(default)]
+    offset: Vec2,
+}
+impl EmitterData {
+    /// Interpolated color for the given fraction of the lifetime
+    fn color(&self, fraction: f32) -> Color {
+        let Some(last) = self.colors.len().checked_sub(1) else {
+            return Color::WHITE;
+        };
+
+        let pos = fraction.clamp(0., 1.) * last as f32;
+        let index = (pos.floor() as usize).min(last);
+        let next = (index + 1).min(last);
+        self.colors[index]
+            .mix(&self.colors[next], pos - index as f32)
+            .into()
+    }
+}
+
+/// Handle for [`ParticleData`]
+#[derive(Resource)]
+pub(crate) struct ParticleHandle(pub(crate) Handle<ParticleData>);
+
+/// Message that is written to spawn a burst of particles
+#[derive(Message, Debug, Clone, Copy)]
+pub(crate) struct SpawnParticles {
+    pub(crate) effect: ParticleEffect,
+    /// Position in world space
+    pub(crate) pos: Vec2,
+}
+
+/// Emitter that spawns particles continuously at its position
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub(crate) struct ParticleEmitter {
+    pub(crate) effect: ParticleEffect,
+    /// Fraction of a particle that is carried over to the next frame
+    accumulated: f32,
+}
+impl ParticleEmitter {
+    pub(crate) fn new(effect: ParticleEffect) -> Self {
+        Self {
+            effect,
+            accumulated: 0.,
+        }
+    }
+}
+
+/// Particle that is stored in a pool
+///
+/// Inactive particles are hidden and can be reused.
+#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+struct Particle {
+    active: bool,
+    effect: ParticleEffect,
+    velocity: Vec2,
+    timer: Timer,
+}
+impl Default for Particle {
+    fn default() -> Self {
+        Self {
+            active: false,
+            effect: ParticleEffect::Dust,
+            velocity: Vec2::ZERO,
+            timer: Timer::default(),
+        }
+    }
+}
+impl Particle {
+    /// Priority for reuse where inactive particles are preferred over the oldest active particle
+    fn reuse_priority(&self) -> f32 {
+        if self.active {
+            self.timer.fraction()
+        } else {
+            f32::INFINITY
+        }
+    }
+}
+
+/// Rng for particles
+#[derive(Component)]
+struct ParticleRng;
+
+/// Spawn [`ParticleRng`] by forking [`GlobalRng`]
+fn setup_rng(mut global: Single<&mut WyRand, With<GlobalRng>>, mut commands: Commands) {
+    commands.spawn((ParticleRng, global.fork_seed()));
+}
+
+/// Deserialize ron file for [`ParticleData`]
+fn setup_particle_data(mut commands: Commands, assets: Res<AssetServer>) {
+    let handle = ParticleHandle(assets.load(PARTICLE_DATA_PATH));
+    commands.insert_resource(handle);
+}
+
+/// Track loading of [`ParticleData`]
+fn track_particle_data(
+    handle: Option<Res<ParticleHandle>>,
+    data: Res<Assets<ParticleData>>,
+) -> Progress {
+    let done = handle.is_some_and(|handle| data.contains(handle.0.id()));
+    Progress {
+        done: done as u32,
+        total: 1,
+    }
+}
+
+/// Spawn hidden particles for the pool
+fn spawn_particle_pool(mut commands: Commands) {
+    for _ in 0..PARTICLE_POOL_SIZE {
+        commands.spawn((
+            Name::new("Particle"),
+            Particle::default(),
+            Sprite::from_color(Color::WHITE, Vec2::ONE),
+            Transform::from_translation(Vec3::Z * DEFAULT_Z),
+            YSort(DEFAULT_Z),
+            Visibility::Hidden,
+            DespawnOnExit(Screen::Gameplay),
+        ));
+    }
+}
+
+/// Activate particles from the pool for every [`SpawnParticles`] and [`ParticleEmitter`]
+///
+/// Messages spawn a burst and emitters spawn particles depending on their rate.
+fn emit_particles(
+    mut rng: Single<&mut WyRand, With<ParticleRng>>,
+    mut msgs: MessageReader<SpawnParticles>,
+    mut emitter_query: Query<(&mut ParticleEmitter, &GlobalTransform)>,
+    mut query: Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    data: Res<Assets<ParticleData>>,
+    handle: Res<ParticleHandle>,
+    settings: Res<Settings>,
+    time: Res<Time>,
+) {
+    // Do not emit particles if motion is reduced
+    if settings.accessibility.reduce_motion {
+        msgs.clear();
+        return;
+    }
+
+    // Get data from `ParticleData` with `ParticleHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_PARTICLE_DATA);
+
+    // Collect effects with position and number of particles
+    let mut requests: Vec<_> = msgs
+        .read()
+        .filter_map(|msg| {
+            let Some(emitter) = data.emitters.get(&msg.effect) else {
+                warn_once!("{} {:?}", WARN_MISSING_PARTICLE_EMITTER, msg.effect);
+                return None;
+            };
+            Some((msg.effect, msg.pos, emitter.burst))
+        })
+        .collect();
+    for (mut emitter, transform) in &mut emitter_query {
+        let Some(emitter_data) = data.emitters.get(&emitter.effect) else {
+            warn_once!("{} {:?}", WARN_MISSING_PARTICLE_EMITTER, emitter.effect);
+            continue;
+        };
+
+        // Carry over fractions of a particle to the next frame
+        emitter.accumulated += emitter_data.rate * time.delta_secs();
+        let count = emitter.accumulated.floor();
+        emitter.accumulated -= count;
+        requests.push((emitter.effect, transform.translation().xy(), count as u32));
+    }
+
+    // Sort pool by priority for reuse
+    let mut pool: Vec<_> = query.iter_mut().collect();
+    pool.sort_by(|(a, ..), (b, ..)| b.reuse_priority().total_cmp(&a.reuse_priority()));
+    let mut pool = pool.into_iter();
+
+    for (effect, pos, count) in requests {
+        let Some(emitter) = data.emitters.get(&effect) else {
+            continue;
+        };
+
+        for _ in 0..count {
+            // Return if the pool is exhausted for this frame
+            let Some((mut particle, mut transform, mut sprite, mut visibility)) = pool.next()
+            else {
+                return;
+            };
+
+            // Choose random velocity within cone, speed and lifetime
+            // NOTE: Negative spreads from the ron file are treated as positive.
+            let half_spread = emitter.spread_deg.abs() / 2.;
+            let angle = (emitter.direction_deg
+                + random_in(rng.as_mut(), (-half_spread, half_spread)))
+            .to_radians();
+            let speed = random_in(rng.as_mut(), emitter.speed);
+            let lifetime = random_in(rng.as_mut(), emitter.lifetime_secs).max(f32::EPSILON);
+
+            particle.active = true;
+            particle.effect = effect;
+            particle.velocity = Vec2::from_angle(angle) * speed;
+            particle.timer = Timer::from_seconds(lifetime, TimerMode::Once);
+            transform.translation = (pos + emitter.offset).extend(DEFAULT_Z);
+            sprite.custom_size = Some(Vec2::splat(emitter.size));
+            sprite.color = emitter.color(0.);
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
+
+/// Move particles, apply gravity and color over life and return them to the pool after
+fn update_particles(
+    mut query: Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    data: Res<Assets<ParticleData>>,
+    handle: Res<ParticleHandle>,
+    time: Res<Time>,
+) {
+    // Get data from `ParticleData` with `ParticleHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_PARTICLE_DATA);
+
+    for (mut particle, mut transform, mut sprite, mut visibility) in &mut query {
+        // Continue if particle is in the pool
+        if !particle.active {
+            continue;
+        }
+
+        // Return particle to the pool if it has expired or its emitter is missing
+        let Some(emitter) = data
+            .emitters
+            .get(&particle.effect)
+            .filter(|_| !particle.timer.is_finished())
+        else {
+            particle.active = false;
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let delta = time.delta_secs();
+        particle.velocity += emitter.gravity * delta;
+        transform.translation += (particle.velocity * delta).extend(0.);
+        sprite.color = emitter.color(particle.timer.fraction());
+    }
+}
+
+/// Tick particle timers
+fn tick_particle_timers(mut query: Query<&mut Particle>, time: Res<Time>) {
+    for mut particle in &mut query {
+        if particle.active {
+            particle.timer.tick(time.delta());
+        }
+    }
+}
+
+/// Random value between minimum and maximum
+///
+/// Swapped bounds from the ron file are sorted so that the range is never empty.
+fn random_in(rng: &mut WyRand, (a, b): (f32, f32)) -> f32 {
+    let (min, max) = (a.min(b), a.max(b));
+    if max > min {
+        rng.random_range(min..=max)
+    } else {
+        min
+    }
+}
+
+/// Write [`SpawnParticles`] at the feet of characters on animation events
+///
+/// ## Traits
+///
+/// - `T` must implement [`Character`].
+pub(crate) fn spawn_animation_particles<T>(
+    query: Query<&Transform, With<T>>,
+    mut msgs: MessageReader<AnimationEvent>,
+    mut particle_msgs: MessageWriter<SpawnParticles>,
+    data: Res<Assets<CollisionData<T>>>,
+    handle: Res<CollisionHandle<T>>,
+) where
+    T: Character,
+{
+    // Get data from `CollisionData` with `CollisionHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_COLLISION_DATA);
+
+    for msg in msgs.read() {
+        // Match to `AnimationEventKind` of the event
+        let effect = match msg.kind {
+            AnimationEventKind::Footstep => ParticleEffect::Footstep,
+            AnimationEventKind::SpawnDust => ParticleEffect::Dust,
+            _ => continue,
+        };
+
+        // Continue if this event is not for this character
+        let Ok(transform) = query.get(msg.entity) else {
+            continue;
+        };
+
+        particle_msgs.write(SpawnParticles {
+            effect,
+            pos: transform.translation.xy() + data.shadow_pos(),
+        });
+    }
+}
@@ -183,10 +183,11 @@ pub(crate) fn despawn_procgen<T, A, const NEXT_STATE: bool>(
     let data = data.get(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
     let tile_size = Vec2::new(data.tile_height, data.tile_width);
 
+    let chunk_size_px = CHUNK_SIZE.as_vec2() * tile_size;
+    let camera_chunk_pos = (camera.translation.xy() / chunk_size_px).floor().as_ivec2();
+
     // Despawn entities outside of `DESPAWN_RANGE`
     for (entity, transform) in query.iter() {
-        let chunk_size_px = CHUNK_SIZE.as_vec2() * tile_size;
-        let camera_chunk_pos = (camera.translation.xy() / chunk_size_px).floor().as_ivec2();
         let chunk_pos = (transform.translation.xy() / chunk_size_px)
             .floor()
             .as_ivec2();
@@ -198,6 +199,12 @@ pub(crate) fn despawn_procgen<T, A, const NEXT_STATE: bool>(
         }
     }
 
+    // Remove entities that have been despawned elsewhere once their chunk is outside of `DESPAWN_RANGE`
+    controller.positions.retain(|entity, chunk_pos| {
+        query.contains(*entity)
+            || camera_chunk_pos.chebyshev_distance(*chunk_pos) <= PROCGEN_DISTANCE as u32
+    });
+
     // Transition state if required
     if NEXT_STATE {
         procgen_state.set(ProcGenState::Spawn);
@@ -160,3 +160,44 @@ fn spawn_chunk<T, A>(
     // Add chunk container to level so that level handles despawning
     commands.entity(level).add_child(container);
 }
+
+/// Update tile sizes and positions of existing chunks if [`TileData`] has been modified
+///
+/// The nav grid is only rebuilt if the tile size has changed.
+///
+/// ## Traits
+///
+/// - `T` must implement [`ProcGenerated`]' and is used as the procedurally generated level associated with a [`ProcGenController<T>`].
+pub(crate) fn reload_chunks<T>(
+    mut query: Query<(&mut TilemapGridSize, &mut TilemapTileSize, &mut Transform)>,
+    mut procgen_state: ResMut<NextState<ProcGenState>>,
+    controller: Res<ProcGenController<T>>,
+    data: Res<Assets<TileData<T>>>,
+    handle: Res<TileHandle<T>>,
+) where
+    T: ProcGenerated,
+{
+    // Get data from `TileData` with `TileHandle`
+    let data = data.get(handle.0.id()).expect(ERR_LOADING_TILE_DATA);
+    let tile_size = Vec2::new(data.tile_height, data.tile_width);
+
+    let mut tile_size_changed = false;
+    for (entity, chunk_pos) in &controller.positions {
+        // Continue if entity is not a chunk
+        let Ok((mut grid_size, mut chunk_tile_size, mut transform)) = query.get_mut(*entity) else {
+            continue;
+        };
+
+        tile_size_changed |= *chunk_tile_size != tile_size.into();
+        *grid_size = tile_size.into();
+        *chunk_tile_size = tile_size.into();
+        let world_pos = chunk_pos.as_vec2() * CHUNK_SIZE.as_vec2() * tile_size;
+        transform.translation = world_pos.extend(LEVEL_Z);
+    }
+
+    // Rebuild nav grid only if tile sizes have changed since they map positions to nav grid cells
+    // NOTE: Tile textures do not affect walkability, so changing them does not need a rebuild.
+    if tile_size_changed {
+        procgen_state.set(ProcGenState::RebuildNavGrid);
+    }
+}
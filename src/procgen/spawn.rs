@@ -13,7 +13,7 @@ use rand::{Rng as _, seq::IndexedRandom as _};
 
 use crate::{
     characters::{
-        Character, CollisionData, CollisionHandle, Shadow, VisualMap,
+        Character, CollisionData, CollisionHandle, Shadow, StatsData, StatsHandle,
         animations::{ANIMATION_DELAY_RANGE, AnimationRng, Animations},
     },
     levels::Level,
@@ -38,12 +38,13 @@ pub(crate) fn spawn_characters<T, A, B>(
     mut commands: Commands,
     mut controller: ResMut<ProcGenController<T>>,
     mut procgen_state: ResMut<NextState<ProcGenState>>,
-    mut visual_map: ResMut<VisualMap>,
     animations: Res<Animations<T>>,
     chunk_controller: Res<ProcGenController<A>>,
     collision_data: Res<Assets<CollisionData<T>>>,
     collision_handle: Res<CollisionHandle<T>>,
     shadow: Res<Shadow<T>>,
+    stats_data: Res<Assets<StatsData<T>>>,
+    stats_handle: Option<Res<StatsHandle<T>>>,
     tile_data: Res<Assets<TileData<A>>>,
     tile_handle: Res<TileHandle<A>>,
 ) where
@@ -60,7 +61,8 @@ pub(crate) fn spawn_characters<T, A, B>(
     let data = collision_data
         .get(collision_handle.0.id())
         .expect(ERR_LOADING_COLLISION_DATA);
-    let data = (data.shape.clone(), data.width, data.height);
+    // Get stats from `StatsData` with `StatsHandle` if the character has stats
+    let stats = stats_handle.and_then(|handle| stats_data.get(handle.0.id()));
 
     // FIXME: Use noise for spawning positions
     for (_, chunk_pos) in &chunk_controller.positions {
@@ -75,10 +77,10 @@ pub(crate) fn spawn_characters<T, A, B>(
             &mut rng,
             &mut commands,
             &mut controller,
-            &mut visual_map,
             level.entity(),
             &animations,
-            &data,
+            data,
+            stats,
             &shadow,
             chunk_pos,
             &tile_size,
@@ -101,10 +103,10 @@ fn spawn_character<T>(
     rng: &mut WyRand,
     commands: &mut Commands,
     controller: &mut ResMut<ProcGenController<T>>,
-    visual_map: &mut ResMut<VisualMap>,
     level: Entity,
     animations: &Res<Animations<T>>,
-    data: &(Option<String>, Option<f32>, Option<f32>),
+    data: &CollisionData<T>,
+    stats: Option<&StatsData<T>>,
     shadow: &Res<Shadow<T>>,
     chunk_pos: &IVec2,
     tile_size: &Vec2,
@@ -129,9 +131,7 @@ fn spawn_character<T>(
         );
 
         // Spawn entity in chosen tile and store in controller
-        let entity = T::spawn(
-            commands, visual_map, data, target_pos, animations, shadow, delay,
-        );
+        let entity = T::spawn(commands, data, stats, target_pos, animations, shadow, delay);
         controller.positions.insert(entity, *chunk_pos);
 
         // Add entity to level so that level handles despawning
@@ -39,5 +39,6 @@ pub(crate) enum Screen {
     LoadingExit,
     Splash,
     Title,
+    CharacterLoading,
     Gameplay,
 }
@@ -14,13 +14,20 @@
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
 use crate::{
-    Pause,
-    characters::{npc::Slime, player::Player, setup_shadow},
+    AppSystems, PausableSystems, Pause, asset_modified,
+    audio::director::update_music_intensity,
+    characters::{
+        damage::{Health, damage_on_contact},
+        goo::{drop_goo, release_despawned_goo, slow_on_goo},
+        npc::Slime,
+        player::Player,
+        setup_shadow,
+    },
     levels::overworld::{Overworld, OverworldAssets, OverworldProcGen, spawn_overworld},
     menus::Menu,
     procgen::{
-        ProcGenState,
-        chunks::spawn_chunks,
+        ProcGenState, TileData,
+        chunks::{reload_chunks, spawn_chunks},
         clear_procgen_controller, despawn_procgen,
         navigation::{
             follow_character, rebuild_nav_grid, spawn_nav_grid, update_nav_grid_agent_pos,
@@ -28,6 +35,7 @@ use crate::{
         spawn::spawn_characters,
     },
     screens::Screen,
+    settings::bindings::{PlayerAction, action_just_pressed},
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -48,6 +56,7 @@ pub(super) fn plugin(app: &mut App) {
             (
                 despawn_procgen::<Slime, OverworldProcGen, false>,
                 despawn_procgen::<OverworldProcGen, OverworldProcGen, true>,
+                release_despawned_goo::<OverworldProcGen>,
             )
                 .chain()
                 .run_if(in_state(ProcGenState::Despawn).and(in_state(Screen::Gameplay))),
@@ -62,6 +71,13 @@ pub(super) fn plugin(app: &mut App) {
         ),
     );
 
+    // Hot-reload tile data of existing chunks
+    app.add_systems(
+        Update,
+        reload_chunks::<OverworldProcGen>
+            .run_if(in_state(Screen::Gameplay).and(asset_modified::<TileData<OverworldProcGen>>)),
+    );
+
     // Update agent pos after exiting `ProcGenState::RebuildNavGrid`
     app.add_systems(
         OnExit(ProcGenState::RebuildNavGrid),
@@ -74,19 +90,66 @@ pub(super) fn plugin(app: &mut App) {
             .run_if(in_state(Screen::Gameplay)),
     );
 
-    // Open pause on pressing P or Escape and pause game
+    // Drive music intensity from slimes chasing the player
+    app.add_systems(
+        Update,
+        update_music_intensity::<Slime, Player>
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+
+    // Damage characters on contact
+    app.add_systems(
+        Update,
+        (
+            damage_on_contact::<Slime, Player>,
+            damage_on_contact::<Player, Slime>,
+        )
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+
+    // Leave goo behind slimes and slow down player on goo
+    app.add_systems(
+        Update,
+        (
+            drop_goo::<Slime, OverworldProcGen>,
+            slow_on_goo::<Player, OverworldProcGen>,
+        )
+            .chain()
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+
+    // Return to title if player has no health left
+    app.add_systems(
+        Update,
+        exit_on_death
+            .run_if(in_state(Screen::Gameplay))
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems),
+    );
+
+    // Open pause on pressing pause bindings and pause game
+    // NOTE: Escape is excluded from closing since it is used to go back in menus and the controls
+    //       menu is excluded since it captures pressed inputs.
     app.add_systems(
         Update,
         (
             (pause, spawn_pause_overlay, open_pause_menu).run_if(
                 in_state(Screen::Gameplay)
                     .and(in_state(Menu::None))
-                    .and(input_just_pressed(KeyCode::KeyP).or(input_just_pressed(KeyCode::Escape))),
+                    .and(action_just_pressed(PlayerAction::Pause)),
             ),
             close_menu.run_if(
                 in_state(Screen::Gameplay)
                     .and(not(in_state(Menu::None)))
-                    .and(input_just_pressed(KeyCode::KeyP)),
+                    .and(not(in_state(Menu::Controls)))
+                    .and(action_just_pressed(PlayerAction::Pause))
+                    .and(not(input_just_pressed(KeyCode::Escape))),
             ),
         ),
     );
@@ -121,6 +184,16 @@ fn pause(mut next_pause: ResMut<NextState<Pause>>) {
     next_pause.set(Pause(true));
 }
 
+/// Exit to title screen if the player has no health left
+fn exit_on_death(
+    health: Single<&Health, With<Player>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if health.current <= 0. {
+        next_screen.set(Screen::Title);
+    }
+}
+
 /// Reset [`ProcGenState`]
 fn reset_procgen_state(mut procgen_state: ResMut<NextState<ProcGenState>>) {
     procgen_state.set(ProcGenState::default());
@@ -14,19 +14,28 @@
 //! A loading screen during which game assets are loaded if necessary.
 //! This reduces stuttering, especially for audio on Wasm.
 
-use bevy::prelude::*;
+#[cfg(not(target_family = "wasm"))]
+use std::path::{Path, PathBuf};
+
+use bevy::{asset::LoadState, prelude::*};
+#[cfg(not(target_family = "wasm"))]
+use bevy::{
+    asset::io::AssetSourceId,
+    tasks::{block_on, futures_lite::StreamExt as _},
+};
 use bevy_asset_loader::prelude::*;
 use bevy_common_assets::ron::RonAssetPlugin;
-use iyes_progress::ProgressPlugin;
+use iyes_progress::prelude::*;
 
 use crate::{
     characters::{
-        CollisionData, CollisionHandle,
-        animations::{AnimationData, AnimationHandle},
-        npc::{Slime, SlimeAssets},
-        player::{Player, PlayerAssets},
+        definition::CharacterData,
+        player::{PLAYER_DEFINITIONS_DIR, Player, PlayerDefinition, PlayerDefinitions},
     },
     levels::overworld::{OverworldAssets, OverworldProcGen},
+    logging::error::{
+        ERR_FAILED_CHARACTER_DATA, ERR_INVALID_COLLISION_DATA, ERR_MISSING_PLAYER_STATS_DATA,
+    },
     menus::credits::CreditsAssets,
     procgen::{TileData, TileHandle},
     screens::{Screen, splash::SplashAssets},
@@ -40,17 +49,13 @@ pub(super) fn plugin(app: &mut App) {
     ));
 
     // Add ron asset plugins
-    app.add_plugins((
-        // levels
-        RonAssetPlugin::<TileData<OverworldProcGen>>::new(&["tiles.ron"]),
-        // characters
-        RonAssetPlugin::<AnimationData<Player>>::new(&["animation.ron"]),
-        RonAssetPlugin::<CollisionData<Player>>::new(&["collision.ron"]),
-        RonAssetPlugin::<AnimationData<Slime>>::new(&["animation.ron"]),
-        RonAssetPlugin::<CollisionData<Slime>>::new(&["collision.ron"]),
-    ));
+    // NOTE: Characters add their own ron asset plugins via `CharacterPlugin`.
+    app.add_plugins(RonAssetPlugin::<TileData<OverworldProcGen>>::new(&[
+        "tiles.ron",
+    ]));
 
     // Add loading states via bevy_asset_loader
+    // NOTE: Characters configure this loading state with their assets via `CharacterPlugin`.
     app.add_loading_state(
         LoadingState::new(Screen::Loading)
             .load_collection::<InteractionAssets>()
@@ -59,34 +64,40 @@ pub(super) fn plugin(app: &mut App) {
             .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
                 "data/levels/overworld.assets.ron",
             )
-            .load_collection::<OverworldAssets>()
-            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
-                "data/characters/player/male.assets.ron",
-            )
-            .load_collection::<PlayerAssets>()
-            .with_dynamic_assets_file::<StandardDynamicAssetCollection>(
-                "data/characters/npc/slime.assets.ron",
-            )
-            .load_collection::<SlimeAssets>(),
+            .load_collection::<OverworldAssets>(),
+    );
+    // NOTE: Selectable characters configure this loading state with their assets via `CharacterPlugin`.
+    app.add_loading_state(
+        LoadingState::new(Screen::CharacterLoading).continue_to_state(Screen::Gameplay),
     );
 
     // Spawn loading screen
     app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen);
+    app.add_systems(OnEnter(Screen::CharacterLoading), spawn_loading_screen);
+
+    // Load player definitions and their preview images
+    app.add_systems(OnEnter(Screen::Loading), setup_player_definitions);
+    app.add_systems(
+        Update,
+        track_player_definitions
+            .track_progress::<Screen>()
+            .run_if(in_state(Screen::Loading)),
+    );
 
     // After initial `LoadingState<Screen::Loading>`, run other requirements before switching to `Screen::LoadingExit`
     app.add_systems(
         Update,
-        (setup_overworld, setup_player, setup_slime)
+        setup_overworld
             .run_if(in_state(Screen::Loading))
             .after(LoadingStateSet(Screen::Loading)),
     );
 }
 
 /// Display loading screen
-fn spawn_loading_screen(mut commands: Commands) {
+fn spawn_loading_screen(mut commands: Commands, screen: Res<State<Screen>>) {
     commands.spawn((
         widgets::common::ui_root("Loading Screen"),
-        DespawnOnExit(Screen::Loading),
+        DespawnOnExit(*screen.get()),
         children![widgets::common::label("Loading...")],
     ));
 }
@@ -97,24 +108,114 @@ fn setup_overworld(mut commands: Commands, assets: Res<AssetServer>) {
     commands.insert_resource(handle);
 }
 
-/// Deserialize ron file for [`CollisionData`]
-fn setup_player(mut commands: Commands, assets: Res<AssetServer>) {
-    let handle =
-        CollisionHandle::<Player>(assets.load("data/characters/player/male.collision.ron"));
-    commands.insert_resource(handle);
+/// Player definitions in [`PLAYER_DEFINITIONS_DIR`] for platforms that can not read directories
+#[cfg(target_family = "wasm")]
+const PLAYER_DEFINITIONS: &[&str] = &["male.character.ron", "scout.character.ron"];
 
-    let handle =
-        AnimationHandle::<Player>(assets.load("data/characters/player/male.animation.ron"));
-    commands.insert_resource(handle);
+/// Load all player definitions in [`PLAYER_DEFINITIONS_DIR`]
+fn setup_player_definitions(mut commands: Commands, assets: Res<AssetServer>) {
+    #[cfg(not(target_family = "wasm"))]
+    let paths = read_player_definitions(&assets);
+    #[cfg(target_family = "wasm")]
+    let paths = PLAYER_DEFINITIONS
+        .iter()
+        .map(|file| format!("{PLAYER_DEFINITIONS_DIR}/{file}"));
+
+    let definitions = paths
+        .into_iter()
+        .map(|path| PlayerDefinition {
+            handle: assets.load(path),
+            image: None,
+        })
+        .collect();
+    commands.insert_resource(PlayerDefinitions(definitions));
 }
 
-/// Deserialize ron file for [`CollisionData`]
-fn setup_slime(mut commands: Commands, assets: Res<AssetServer>) {
-    // Collisions
-    let handle = CollisionHandle::<Slime>(assets.load("data/characters/npc/slime.collision.ron"));
-    commands.insert_resource(handle);
+/// Read paths of all `*.character.ron` files in [`PLAYER_DEFINITIONS_DIR`]
+#[cfg(not(target_family = "wasm"))]
+fn read_player_definitions(assets: &AssetServer) -> Vec<PathBuf> {
+    let Ok(source) = assets.get_source(AssetSourceId::Default) else {
+        return Vec::new();
+    };
 
-    // Animations
-    let handle = AnimationHandle::<Slime>(assets.load("data/characters/npc/slime.animation.ron"));
-    commands.insert_resource(handle);
+    let mut paths: Vec<PathBuf> = block_on(async {
+        let Ok(paths) = source
+            .reader()
+            .read_directory(Path::new(PLAYER_DEFINITIONS_DIR))
+            .await
+        else {
+            return Vec::new();
+        };
+        paths.collect().await
+    });
+    paths.retain(|path| path.to_string_lossy().ends_with(".character.ron"));
+
+    // Sort paths to keep the order stable between runs
+    paths.sort();
+    paths
+}
+
+/// Track loading of player definitions and load their preview images
+///
+/// Definitions that could not be read or parsed, with invalid collision data or without stats are
+/// reported with the path of the file and removed so that they can not be selected.
+fn track_player_definitions(
+    mut definitions: ResMut<PlayerDefinitions>,
+    data: Res<Assets<CharacterData<Player>>>,
+    assets: Res<AssetServer>,
+) -> Progress {
+    // Remove definitions that failed to load, with invalid collision data or without stats
+    definitions.0.retain(|definition| {
+        let path = || {
+            definition
+                .handle
+                .path()
+                .map(ToString::to_string)
+                .unwrap_or_default()
+        };
+        let Some(data) = data.get(definition.handle.id()) else {
+            if let LoadState::Failed(err) = assets.load_state(definition.handle.id()) {
+                error!("{} {} ({})", ERR_FAILED_CHARACTER_DATA, path(), err);
+                return false;
+            }
+            return true;
+        };
+        let err = if !data.is_valid() {
+            ERR_INVALID_COLLISION_DATA
+        } else if data.stats.is_none() {
+            ERR_MISSING_PLAYER_STATS_DATA
+        } else {
+            return true;
+        };
+
+        error!("{} {}", err, path());
+        false
+    });
+
+    let mut progress = Progress {
+        done: 0,
+        total: definitions.0.len() as u32 * 2,
+    };
+
+    for definition in &mut definitions.0 {
+        // Continue if definition has not been loaded
+        let Some(data) = data.get(definition.handle.id()) else {
+            continue;
+        };
+        progress.done += 1;
+
+        // Load preview image
+        let Some(path) = data.image_path() else {
+            progress.done += 1;
+            continue;
+        };
+        let image = definition
+            .image
+            .get_or_insert_with(|| assets.load(path.to_string()));
+        if assets.is_loaded_with_dependencies(image.id()) {
+            progress.done += 1;
+        }
+    }
+
+    progress
 }
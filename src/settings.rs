@@ -0,0 +1,546 @@
+/*
+ * File: settings.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! User settings that are persisted across sessions.
+//!
+//! [`Settings`] is loaded before the splash screen and saved whenever it changes. It is stored via
+//! [`SettingsBackend`] which can be replaced by inserting it before adding this plugin.
+
+pub(crate) mod bindings;
+
+#[cfg(not(target_family = "wasm"))]
+use std::path::PathBuf;
+
+use bevy::{
+    audio::Volume,
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, VideoModeSelection, WindowMode},
+};
+use ron::ser::PrettyConfig;
+
+use crate::{
+    RES_HEIGHT,
+    audio::{AudioBus, AudioBuses, director::MusicMode},
+    logging::warn::{WARN_INVALID_SETTINGS, WARN_SAVING_SETTINGS},
+    settings::bindings::InputBindings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    // Insert default storage backend if none has been inserted
+    if !app.world().contains_resource::<SettingsBackend>() {
+        app.insert_resource(SettingsBackend::default());
+    }
+
+    // Load settings before anything else
+    app.add_systems(PreStartup, load_settings);
+
+    // Apply and save settings if they are changed
+    app.add_systems(
+        Update,
+        (
+            apply_audio_settings,
+            apply_display_settings,
+            save_settings.run_if(not(resource_added::<Settings>)),
+        )
+            .run_if(resource_changed::<Settings>),
+    );
+}
+
+/// Current version of [`Settings`]
+///
+/// Increase this if existing fields change their meaning and handle it in [`Settings::migrate`].
+const SETTINGS_VERSION: u32 = 2;
+
+/// Settings of the user
+///
+/// Missing fields are filled with defaults so that settings from older versions stay valid.
+#[derive(Resource, serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub(crate) struct Settings {
+    /// Version that the settings have been saved with
+    version: u32,
+    pub(crate) audio: AudioSettings,
+    pub(crate) display: DisplaySettings,
+    pub(crate) bindings: InputBindings,
+    pub(crate) accessibility: AccessibilitySettings,
+}
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            version: SETTINGS_VERSION,
+            audio: AudioSettings::default(),
+            display: DisplaySettings::default(),
+            bindings: InputBindings::default(),
+            accessibility: AccessibilitySettings::default(),
+        }
+    }
+}
+impl Settings {
+    /// Upgrade settings that have been saved with an older version
+    ///
+    /// New fields are filled by `serde(default)`. Bindings of version 1 that only contain key codes
+    /// are read as [`InputBinding::Key`](bindings::InputBinding::Key) while deserializing.
+    fn migrate(mut self) -> Self {
+        // Version 1 stored fullscreen as a flag that is replaced by the window mode
+        if self.version < 2 && self.display.fullscreen {
+            self.display.window_mode = DisplayMode::Fullscreen;
+        }
+        self.display.fullscreen = false;
+
+        self.version = SETTINGS_VERSION;
+        self
+    }
+
+    /// Clamp values that might have been modified outside of the game
+    ///
+    /// Volumes are clamped to `0..=1` and the resolution is snapped to the nearest one in [`RESOLUTIONS`].
+    fn validate(mut self) -> Self {
+        // Clamp volumes
+        self.audio.master = clamp_volume(self.audio.master);
+        for bus in AudioBus::ALL {
+            let bus = self.audio.buses.get_mut(bus);
+            bus.volume = clamp_volume(bus.volume);
+        }
+
+        // Snap resolution to the nearest selectable one
+        let resolution = self.display.resolution;
+        self.display.resolution = RESOLUTIONS
+            .into_iter()
+            .min_by(|a, b| (a - resolution).abs().total_cmp(&(b - resolution).abs()))
+            .unwrap_or(RES_HEIGHT);
+
+        self
+    }
+}
+
+/// Clamp a linear volume to `0..=1` where invalid volumes are muted
+fn clamp_volume(volume: f32) -> f32 {
+    if volume.is_nan() {
+        return 0.;
+    }
+    volume.clamp(0., 1.)
+}
+
+/// Audio settings
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub(crate) struct AudioSettings {
+    /// Linear volume that is applied to all audio
+    pub(crate) master: f32,
+    pub(crate) buses: AudioBuses,
+    pub(crate) music_mode: MusicMode,
+}
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master: 1.,
+            buses: AudioBuses::default(),
+            music_mode: MusicMode::default(),
+        }
+    }
+}
+
+/// Display settings
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub(crate) struct DisplaySettings {
+    pub(crate) window_mode: DisplayMode,
+    pub(crate) vsync: bool,
+    pub(crate) scaling: CanvasScaling,
+    /// Internal resolution height in pixels
+    pub(crate) resolution: f32,
+    /// Fullscreen flag of version 1 that is only read to migrate it to `window_mode`
+    #[serde(skip_serializing)]
+    fullscreen: bool,
+}
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            window_mode: DisplayMode::default(),
+            vsync: true,
+            scaling: CanvasScaling::default(),
+            resolution: RES_HEIGHT,
+            fullscreen: false,
+        }
+    }
+}
+
+/// Internal resolution heights that can be selected
+pub(crate) const RESOLUTIONS: [f32; 3] = [RES_HEIGHT, 240., 360.];
+
+/// Mode of the primary window
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum DisplayMode {
+    #[default]
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+impl DisplayMode {
+    /// Name that is displayed in settings
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Windowed => "Windowed",
+            Self::Borderless => "Borderless",
+            Self::Fullscreen => "Fullscreen",
+        }
+    }
+
+    /// The next mode
+    pub(crate) fn cycled(self) -> Self {
+        match self {
+            Self::Windowed => Self::Borderless,
+            Self::Borderless => Self::Fullscreen,
+            Self::Fullscreen => Self::Windowed,
+        }
+    }
+
+    /// Mode for [`Window`]
+    fn window_mode(self) -> WindowMode {
+        match self {
+            Self::Windowed => WindowMode::Windowed,
+            Self::Borderless => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            Self::Fullscreen => {
+                WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+            }
+        }
+    }
+}
+
+/// How the canvas is scaled to fit the window
+#[derive(serde::Serialize, serde::Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum CanvasScaling {
+    /// Scale by integer multiples only to keep pixels sharp
+    #[default]
+    Integer,
+    /// Scale to fill the window height
+    Fit,
+}
+impl CanvasScaling {
+    /// Name that is displayed in settings
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::Integer => "Integer",
+            Self::Fit => "Fit",
+        }
+    }
+
+    /// The other scaling
+    pub(crate) fn toggled(self) -> Self {
+        match self {
+            Self::Integer => Self::Fit,
+            Self::Fit => Self::Integer,
+        }
+    }
+}
+
+/// Accessibility settings
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default)]
+pub(crate) struct AccessibilitySettings {
+    /// Disable squash-and-stretch and particles
+    pub(crate) reduce_motion: bool,
+}
+
+/// Applies to anything that can store serialized [`Settings`]
+pub(crate) trait SettingsStorage
+where
+    Self: Send + Sync + 'static,
+{
+    /// Serialized settings or [`None`] if nothing has been stored yet
+    fn load(&self) -> Option<String>;
+
+    /// Store serialized settings
+    fn save(&self, data: &str) -> Result<(), String>;
+}
+
+/// Storage backend that is used to load and save [`Settings`]
+#[derive(Resource)]
+pub(crate) struct SettingsBackend(pub(crate) Box<dyn SettingsStorage>);
+impl Default for SettingsBackend {
+    fn default() -> Self {
+        #[cfg(not(target_family = "wasm"))]
+        return Self(Box::new(FileStorage::default()));
+        #[cfg(target_family = "wasm")]
+        return Self(Box::new(LocalStorage));
+    }
+}
+
+/// Name of the app that is used for the config directory
+#[cfg(not(target_family = "wasm"))]
+const APP_NAME: &str = "bevy-slime-dodge";
+
+/// Stores settings in a ron file in the config directory of the user
+#[cfg(not(target_family = "wasm"))]
+pub(crate) struct FileStorage(Option<PathBuf>);
+#[cfg(not(target_family = "wasm"))]
+impl Default for FileStorage {
+    fn default() -> Self {
+        Self(config_dir().map(|dir| dir.join(APP_NAME).join("settings.ron")))
+    }
+}
+#[cfg(not(target_family = "wasm"))]
+impl SettingsStorage for FileStorage {
+    fn load(&self) -> Option<String> {
+        std::fs::read_to_string(self.0.as_ref()?).ok()
+    }
+
+    fn save(&self, data: &str) -> Result<(), String> {
+        let path = self.0.as_ref().ok_or("No config directory found.")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+        std::fs::write(path, data).map_err(|err| err.to_string())
+    }
+}
+
+/// Config directory of the user depending on the platform
+#[cfg(not(target_family = "wasm"))]
+fn config_dir() -> Option<PathBuf> {
+    let home = || std::env::var_os("HOME").map(PathBuf::from);
+    if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library").join("Application Support"))
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home().map(|home| home.join(".config")))
+    }
+}
+
+/// Stores settings in `localStorage` of the browser
+#[cfg(target_family = "wasm")]
+pub(crate) struct LocalStorage;
+#[cfg(target_family = "wasm")]
+impl LocalStorage {
+    /// Key that settings are stored with
+    const KEY: &str = "bevy-slime-dodge.settings";
+
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+}
+#[cfg(target_family = "wasm")]
+impl SettingsStorage for LocalStorage {
+    fn load(&self) -> Option<String> {
+        Self::storage()?.get_item(Self::KEY).ok()?
+    }
+
+    fn save(&self, data: &str) -> Result<(), String> {
+        Self::storage()
+            .ok_or("No localStorage available.")?
+            .set_item(Self::KEY, data)
+            .map_err(|err| format!("{err:?}"))
+    }
+}
+
+/// Load [`Settings`] from [`SettingsBackend`] and fall back to defaults
+fn load_settings(mut commands: Commands, backend: Res<SettingsBackend>) {
+    let settings = backend
+        .0
+        .load()
+        .and_then(|data| {
+            ron::from_str::<Settings>(&data)
+                .inspect_err(|err| warn!("{} {}", WARN_INVALID_SETTINGS, err))
+                .ok()
+        })
+        .map(Settings::migrate)
+        .map(Settings::validate)
+        .unwrap_or_default();
+    commands.insert_resource(settings);
+}
+
+/// Save [`Settings`] with [`SettingsBackend`]
+fn save_settings(settings: Res<Settings>, backend: Res<SettingsBackend>) {
+    let result = ron::ser::to_string_pretty(settings.as_ref(), PrettyConfig::default())
+        .map_err(|err| err.to_string())
+        .and_then(|data| backend.0.save(&data));
+    if let Err(err) = result {
+        warn!("{} {}", WARN_SAVING_SETTINGS, err);
+    }
+}
+
+/// Apply audio settings to [`GlobalVolume`] and [`AudioBuses`]
+fn apply_audio_settings(
+    settings: Res<Settings>,
+    mut global_volume: ResMut<GlobalVolume>,
+    mut buses: ResMut<AudioBuses>,
+) {
+    let volume = Volume::Linear(settings.audio.master);
+    if global_volume.volume != volume {
+        global_volume.volume = volume;
+    }
+    buses.set_if_neq(settings.audio.buses.clone());
+}
+
+/// Apply display settings to [`PrimaryWindow`]
+fn apply_display_settings(
+    mut window: Single<&mut Window, With<PrimaryWindow>>,
+    settings: Res<Settings>,
+) {
+    let mode = settings.display.window_mode.window_mode();
+    let present_mode = if settings.display.vsync {
+        PresentMode::AutoVsync
+    } else {
+        PresentMode::AutoNoVsync
+    };
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::settings::bindings::{InputBinding, PlayerAction};
+
+    /// Stores settings in memory
+    #[derive(Default, Clone)]
+    struct MemoryStorage(Arc<Mutex<Option<String>>>);
+    impl MemoryStorage {
+        fn with(data: &str) -> Self {
+            Self(Arc::new(Mutex::new(Some(data.to_string()))))
+        }
+    }
+    impl SettingsStorage for MemoryStorage {
+        fn load(&self) -> Option<String> {
+            self.0.lock().unwrap().clone()
+        }
+
+        fn save(&self, data: &str) -> Result<(), String> {
+            *self.0.lock().unwrap() = Some(data.to_string());
+            Ok(())
+        }
+    }
+
+    /// App that stores settings in `storage`
+    fn app(storage: &MemoryStorage) -> App {
+        let mut app = App::new();
+        app.insert_resource(SettingsBackend(Box::new(storage.clone())));
+        app
+    }
+
+    /// Load [`Settings`] from `storage`
+    fn load(storage: &MemoryStorage) -> Settings {
+        let mut app = app(storage);
+        app.world_mut().run_system_once(load_settings).unwrap();
+        app.world_mut().remove_resource::<Settings>().unwrap()
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let storage = MemoryStorage::default();
+        let mut settings = Settings::default();
+        settings.audio.master = 0.5;
+        settings.accessibility.reduce_motion = true;
+
+        let mut app = app(&storage);
+        app.insert_resource(settings.clone());
+        app.world_mut().run_system_once(save_settings).unwrap();
+
+        assert_eq!(load(&storage), settings);
+    }
+
+    #[test]
+    fn missing_fields_are_filled_with_defaults() {
+        let storage = MemoryStorage::with("(audio: (master: 0.5))");
+
+        let settings = load(&storage);
+
+        assert_eq!(settings.audio.master, 0.5);
+        assert_eq!(settings.audio.buses, AudioBuses::default());
+        assert_eq!(settings.display, DisplaySettings::default());
+        assert_eq!(settings.bindings, InputBindings::default());
+        assert_eq!(settings.accessibility, AccessibilitySettings::default());
+    }
+
+    #[test]
+    fn older_version_is_migrated() {
+        let storage = MemoryStorage::with("(version: 0, accessibility: (reduce_motion: true))");
+
+        let settings = load(&storage);
+
+        assert_eq!(settings.version, SETTINGS_VERSION);
+        assert!(settings.accessibility.reduce_motion);
+    }
+
+    #[test]
+    fn fullscreen_of_version_1_is_migrated() {
+        let storage =
+            MemoryStorage::with("(version: 1, display: (fullscreen: true, vsync: false))");
+
+        let settings = load(&storage);
+
+        assert_eq!(settings.display.window_mode, DisplayMode::Fullscreen);
+        assert!(!settings.display.vsync);
+        assert!(!settings.display.fullscreen);
+    }
+
+    #[test]
+    fn key_bindings_of_version_1_are_migrated() {
+        let storage = MemoryStorage::with(
+            "(version: 1, bindings: (jump: [KeyJ, Space], dash: [Key(KeyK), Gamepad(East)]))",
+        );
+
+        let settings = load(&storage);
+
+        assert_eq!(
+            settings.bindings.get(PlayerAction::Jump),
+            &vec![
+                InputBinding::Key(KeyCode::KeyJ),
+                InputBinding::Key(KeyCode::Space)
+            ]
+        );
+        assert_eq!(
+            settings.bindings.get(PlayerAction::Dash),
+            &vec![
+                InputBinding::Key(KeyCode::KeyK),
+                InputBinding::Gamepad(GamepadButton::East)
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_clamped() {
+        let storage = MemoryStorage::with(
+            "(audio: (master: 2., buses: (music: (volume: -1.))), display: (resolution: 350.))",
+        );
+
+        let settings = load(&storage);
+
+        assert_eq!(settings.audio.master, 1.);
+        assert_eq!(settings.audio.buses.get(AudioBus::Music).volume, 0.);
+        assert_eq!(settings.display.resolution, 360.);
+    }
+
+    #[test]
+    fn non_positive_resolution_is_snapped() {
+        let storage = MemoryStorage::with("(display: (resolution: 0.))");
+
+        let settings = load(&storage);
+
+        assert!(RESOLUTIONS.contains(&settings.display.resolution));
+        assert!(settings.display.resolution > 0.);
+    }
+
+    #[test]
+    fn invalid_ron_falls_back_to_defaults() {
+        let storage = MemoryStorage::with("(audio: (master: ");
+
+        assert_eq!(load(&storage), Settings::default());
+    }
+}
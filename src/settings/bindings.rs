@@ -0,0 +1,329 @@
+/*
+ * File: bindings.rs
+ * Author: Leopold Johannes Meinel (leo@meinel.dev)
+ * -----
+ * Copyright (c) 2025 Leopold Johannes Meinel & contributors
+ * SPDX ID: Apache-2.0
+ * URL: https://www.apache.org/licenses/LICENSE-2.0
+ */
+
+//! Rebindable inputs of player actions.
+//!
+//! [`InputBindings`] are stored in [`Settings`] and applied to bevy_enhanced_input when the player
+//! is spawned.
+
+use std::fmt;
+
+use bevy::{
+    ecs::spawn::{SpawnIter, SpawnWith, SpawnableList},
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use serde::{
+    Deserialize as _,
+    de::{EnumAccess, IntoDeserializer as _, VariantAccess as _, Visitor},
+};
+
+use crate::settings::Settings;
+
+/// Key or gamepad button that an action is bound to
+///
+/// Bare key codes from settings version 1 are deserialized as [`InputBinding::Key`].
+#[derive(serde::Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum InputBinding {
+    Key(KeyCode),
+    Gamepad(GamepadButton),
+}
+impl<'de> serde::Deserialize<'de> for InputBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("InputBinding", &["Key", "Gamepad"], InputBindingVisitor)
+    }
+}
+
+/// Visitor for [`InputBinding`] that also accepts bare key codes
+struct InputBindingVisitor;
+impl<'de> Visitor<'de> for InputBindingVisitor {
+    type Value = InputBinding;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an input binding or a key code")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (VariantName(name), variant) = data.variant()?;
+        match name.as_str() {
+            "Key" => variant.newtype_variant().map(InputBinding::Key),
+            "Gamepad" => variant.newtype_variant().map(InputBinding::Gamepad),
+            key => {
+                variant.unit_variant()?;
+                KeyCode::deserialize(key.into_deserializer()).map(InputBinding::Key)
+            }
+        }
+    }
+}
+
+/// Name of an enum variant that is deserialized as an identifier
+struct VariantName(String);
+impl<'de> serde::Deserialize<'de> for VariantName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_identifier(VariantNameVisitor)
+            .map(Self)
+    }
+}
+
+/// Visitor for [`VariantName`]
+struct VariantNameVisitor;
+impl Visitor<'_> for VariantNameVisitor {
+    type Value = String;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a variant name")
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(name.to_string())
+    }
+}
+impl InputBinding {
+    /// Binding for bevy_enhanced_input
+    pub(crate) fn binding(self) -> Binding {
+        match self {
+            Self::Key(key) => key.into(),
+            Self::Gamepad(button) => button.into(),
+        }
+    }
+
+    /// Whether this has just been pressed
+    fn just_pressed(self, keys: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> bool {
+        match self {
+            Self::Key(key) => keys.just_pressed(key),
+            Self::Gamepad(button) => gamepads.iter().any(|gamepad| gamepad.just_pressed(button)),
+        }
+    }
+
+    /// Name that is displayed in the controls menu
+    pub(crate) fn name(self) -> String {
+        match self {
+            Self::Key(key) => {
+                let name = format!("{key:?}");
+                let name = name
+                    .strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .or_else(|| name.strip_prefix("Arrow"))
+                    .unwrap_or(&name);
+                name.to_string()
+            }
+            Self::Gamepad(button) => format!("Pad {button:?}"),
+        }
+    }
+}
+
+/// Action of the player that can be rebound
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub(crate) enum PlayerAction {
+    WalkUp,
+    WalkDown,
+    WalkLeft,
+    WalkRight,
+    Jump,
+    Dash,
+    Sprint,
+    Pause,
+}
+impl PlayerAction {
+    /// All actions in the order they are displayed
+    pub(crate) const ALL: [Self; 8] = [
+        Self::WalkUp,
+        Self::WalkDown,
+        Self::WalkLeft,
+        Self::WalkRight,
+        Self::Jump,
+        Self::Dash,
+        Self::Sprint,
+        Self::Pause,
+    ];
+
+    /// Name that is displayed in the controls menu
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Self::WalkUp => "Walk Up",
+            Self::WalkDown => "Walk Down",
+            Self::WalkLeft => "Walk Left",
+            Self::WalkRight => "Walk Right",
+            Self::Jump => "Jump",
+            Self::Dash => "Dash",
+            Self::Sprint => "Sprint",
+            Self::Pause => "Pause",
+        }
+    }
+}
+
+/// Maximum number of bindings per [`PlayerAction`]
+///
+/// The oldest binding is replaced if another one is added.
+pub(crate) const MAX_BINDINGS: usize = 3;
+
+/// Inputs that are bound to each [`PlayerAction`]
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub(crate) struct InputBindings {
+    walk_up: Vec<InputBinding>,
+    walk_down: Vec<InputBinding>,
+    walk_left: Vec<InputBinding>,
+    walk_right: Vec<InputBinding>,
+    jump: Vec<InputBinding>,
+    dash: Vec<InputBinding>,
+    sprint: Vec<InputBinding>,
+    pause: Vec<InputBinding>,
+}
+impl Default for InputBindings {
+    fn default() -> Self {
+        use InputBinding::{Gamepad, Key};
+
+        Self {
+            walk_up: vec![Key(KeyCode::KeyW), Key(KeyCode::ArrowUp)],
+            walk_down: vec![Key(KeyCode::KeyS), Key(KeyCode::ArrowDown)],
+            walk_left: vec![Key(KeyCode::KeyA), Key(KeyCode::ArrowLeft)],
+            walk_right: vec![Key(KeyCode::KeyD), Key(KeyCode::ArrowRight)],
+            jump: vec![Key(KeyCode::Space), Gamepad(GamepadButton::South)],
+            dash: vec![Key(KeyCode::ShiftLeft), Gamepad(GamepadButton::East)],
+            sprint: vec![Key(KeyCode::ControlLeft), Gamepad(GamepadButton::LeftThumb)],
+            pause: vec![
+                Key(KeyCode::KeyP),
+                Key(KeyCode::Escape),
+                Gamepad(GamepadButton::Start),
+            ],
+        }
+    }
+}
+impl InputBindings {
+    pub(crate) fn get(&self, action: PlayerAction) -> &Vec<InputBinding> {
+        match action {
+            PlayerAction::WalkUp => &self.walk_up,
+            PlayerAction::WalkDown => &self.walk_down,
+            PlayerAction::WalkLeft => &self.walk_left,
+            PlayerAction::WalkRight => &self.walk_right,
+            PlayerAction::Jump => &self.jump,
+            PlayerAction::Dash => &self.dash,
+            PlayerAction::Sprint => &self.sprint,
+            PlayerAction::Pause => &self.pause,
+        }
+    }
+
+    pub(crate) fn get_mut(&mut self, action: PlayerAction) -> &mut Vec<InputBinding> {
+        match action {
+            PlayerAction::WalkUp => &mut self.walk_up,
+            PlayerAction::WalkDown => &mut self.walk_down,
+            PlayerAction::WalkLeft => &mut self.walk_left,
+            PlayerAction::WalkRight => &mut self.walk_right,
+            PlayerAction::Jump => &mut self.jump,
+            PlayerAction::Dash => &mut self.dash,
+            PlayerAction::Sprint => &mut self.sprint,
+            PlayerAction::Pause => &mut self.pause,
+        }
+    }
+
+    /// Other action that `binding` is already bound to
+    pub(crate) fn conflict(
+        &self,
+        action: PlayerAction,
+        binding: InputBinding,
+    ) -> Option<PlayerAction> {
+        PlayerAction::ALL
+            .into_iter()
+            .find(|other| *other != action && self.get(*other).contains(&binding))
+    }
+
+    /// Bind `binding` to `action` and unbind it from a conflicting action
+    ///
+    /// Returns the action that `binding` has been unbound from.
+    pub(crate) fn bind(
+        &mut self,
+        action: PlayerAction,
+        binding: InputBinding,
+    ) -> Option<PlayerAction> {
+        // Unbind from conflicting action
+        let conflict = self.conflict(action, binding);
+        if let Some(other) = conflict {
+            self.get_mut(other).retain(|other| *other != binding);
+        }
+
+        // Add binding and replace the oldest binding if there are too many
+        let bindings = self.get_mut(action);
+        if !bindings.contains(&binding) {
+            if bindings.len() >= MAX_BINDINGS {
+                bindings.remove(0);
+            }
+            bindings.push(binding);
+        }
+
+        conflict
+    }
+
+    /// Bindings of `action` for bevy_enhanced_input
+    fn bindings(&self, action: PlayerAction) -> Vec<Binding> {
+        self.get(action)
+            .iter()
+            .map(|binding| binding.binding())
+            .collect()
+    }
+
+    /// Bindings of the cardinal directions for bevy_enhanced_input with their modifiers
+    ///
+    /// This matches [`Cardinal`] so that each direction can be rebound individually.
+    pub(crate) fn walk_bindings(&self) -> impl SpawnableList<BindingOf> + use<> {
+        let up = self.bindings(PlayerAction::WalkUp);
+        let down = self.bindings(PlayerAction::WalkDown);
+        let left = self.bindings(PlayerAction::WalkLeft);
+        let right = self.bindings(PlayerAction::WalkRight);
+
+        SpawnWith(move |parent: &mut BindingSpawner| {
+            for binding in up {
+                parent.spawn((binding, SwizzleAxis::YXZ));
+            }
+            for binding in down {
+                parent.spawn((binding, Negate::all(), SwizzleAxis::YXZ));
+            }
+            for binding in left {
+                parent.spawn((binding, Negate::all()));
+            }
+            for binding in right {
+                parent.spawn(binding);
+            }
+        })
+    }
+
+    /// Bindings of a button-like `action` for bevy_enhanced_input
+    pub(crate) fn button_bindings(
+        &self,
+        action: PlayerAction,
+    ) -> impl SpawnableList<BindingOf> + use<> {
+        SpawnIter(self.bindings(action).into_iter())
+    }
+}
+
+/// Run condition that is true if any binding of `action` has just been pressed
+pub(crate) fn action_just_pressed(
+    action: PlayerAction,
+) -> impl FnMut(Res<Settings>, Res<ButtonInput<KeyCode>>, Query<&Gamepad>) -> bool + Clone {
+    move |settings, keys, gamepads| {
+        settings
+            .bindings
+            .get(action)
+            .iter()
+            .any(|binding| binding.just_pressed(&keys, &gamepads))
+    }
+}
@@ -9,28 +9,36 @@
  * Heavily inspired by: https://github.com/TheBevyFlock/bevy_new_2d
  */
 
-use crate::audio::sound_effect;
-use bevy::prelude::*;
+use crate::audio::ui_sound;
+use bevy::{
+    input_focus::{InputFocus, InputFocusVisible},
+    prelude::*,
+};
 use bevy_asset_loader::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    // Visualize ui interactions with color palette
+    // Track focused widget and whether focus is visible
+    app.init_resource::<InputFocus>();
+    app.init_resource::<InputFocusVisible>();
+
+    // Visualize ui interactions and focus with color palette
     app.add_systems(Update, apply_interaction_palette);
 
     // Play sound effects
-    app.add_observer(play_on_hover_sound_effect);
-    app.add_observer(play_on_click_sound_effect);
+    app.add_observer(play_on_hover_ui_sound);
+    app.add_observer(play_on_click_ui_sound);
 }
 
 /// Palette for widget interactions. Add this to an entity that supports
 /// [`Interaction`]s, such as a button, to change its [`BackgroundColor`] based
-/// on the current interaction state.
+/// on the current interaction state and whether it has visible [`InputFocus`].
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub(crate) struct InteractionPalette {
     pub(crate) none: Color,
     pub(crate) hovered: Color,
     pub(crate) pressed: Color,
+    pub(crate) focused: Color,
 }
 
 /// Assets for interaction
@@ -42,25 +50,38 @@ pub(crate) struct InteractionAssets {
     click: Handle<AudioSource>,
 }
 
-/// Apply color from palette mapped to interaction
+/// Apply color from palette mapped to interaction and focus
 fn apply_interaction_palette(
-    mut query: Query<
-        (&Interaction, &InteractionPalette, &mut BackgroundColor),
-        Changed<Interaction>,
-    >,
+    mut query: Query<(
+        Entity,
+        Ref<Interaction>,
+        &InteractionPalette,
+        &mut BackgroundColor,
+    )>,
+    focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
 ) {
-    for (interaction, palette, mut background) in &mut query {
-        *background = match interaction {
-            Interaction::None => palette.none,
-            Interaction::Hovered => palette.hovered,
+    let focus_changed = focus.is_changed() || focus_visible.is_changed();
+
+    for (entity, interaction, palette, mut background) in &mut query {
+        // Continue if neither interaction nor focus has changed
+        if !focus_changed && !interaction.is_changed() {
+            continue;
+        }
+
+        let focused = focus_visible.0 && focus.get() == Some(entity);
+        *background = match *interaction {
             Interaction::Pressed => palette.pressed,
+            Interaction::Hovered => palette.hovered,
+            Interaction::None if focused => palette.focused,
+            Interaction::None => palette.none,
         }
         .into();
     }
 }
 
-/// Play sound effect on hover
-fn play_on_hover_sound_effect(
+/// Play UI sound on hover
+fn play_on_hover_ui_sound(
     event: On<Pointer<Over>>,
     query: Query<(), With<Interaction>>,
     mut commands: Commands,
@@ -71,12 +92,12 @@ fn play_on_hover_sound_effect(
     };
 
     if query.contains(event.entity) {
-        commands.spawn(sound_effect(interaction_assets.hover.clone()));
+        commands.spawn(ui_sound(interaction_assets.hover.clone()));
     }
 }
 
-/// Play sound effect on click
-fn play_on_click_sound_effect(
+/// Play UI sound on click
+fn play_on_click_ui_sound(
     event: On<Pointer<Click>>,
     query: Query<(), With<Interaction>>,
     mut commands: Commands,
@@ -87,6 +108,6 @@ fn play_on_click_sound_effect(
     };
 
     if query.contains(event.entity) {
-        commands.spawn(sound_effect(interaction_assets.click.clone()));
+        commands.spawn(ui_sound(interaction_assets.click.clone()));
     }
 }
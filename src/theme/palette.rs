@@ -25,3 +25,12 @@ pub(crate) const BUTTON_BACKGROUND: Srgba = tailwind::CYAN_500;
 pub(crate) const BUTTON_HOVERED_BACKGROUND: Srgba = tailwind::CYAN_300;
 /// rgb(14, 116, 144)
 pub(crate) const BUTTON_PRESSED_BACKGROUND: Srgba = tailwind::CYAN_700;
+/// rgb(45, 212, 191)
+pub(crate) const BUTTON_FOCUSED_BACKGROUND: Srgba = tailwind::TEAL_400;
+
+/// rgba(38, 38, 38, 204)
+pub(crate) const BAR_BACKGROUND: Srgba = Srgba::new(0.149, 0.149, 0.149, 0.8);
+/// rgb(248, 113, 113)
+pub(crate) const HEALTH_BAR_FILL: Srgba = tailwind::RED_400;
+/// rgb(250, 204, 21)
+pub(crate) const STAMINA_BAR_FILL: Srgba = tailwind::YELLOW_400;
@@ -59,6 +59,30 @@ pub(crate) fn label(text: impl Into<String>) -> impl Bundle {
     )
 }
 
+/// A horizontal bar with a fill. The fill's width is meant to be updated via its [`Node`].
+///
+/// `fill` is added to the fill entity and should contain a marker and a [`BackgroundColor`].
+pub(crate) fn bar(name: impl Into<Cow<'static, str>>, fill: impl Bundle) -> impl Bundle {
+    (
+        Name::new(name),
+        Node {
+            width: px(200),
+            height: px(16),
+            ..default()
+        },
+        BackgroundColor(BAR_BACKGROUND.into()),
+        children![(
+            Name::new("Bar Fill"),
+            Node {
+                width: percent(100),
+                height: percent(100),
+                ..default()
+            },
+            fill,
+        )],
+    )
+}
+
 /// A large rounded button with text and an action defined as an [`Observer`].
 ///
 /// ## Traits
@@ -162,6 +186,7 @@ where
                         none: BUTTON_BACKGROUND.into(),
                         hovered: BUTTON_HOVERED_BACKGROUND.into(),
                         pressed: BUTTON_PRESSED_BACKGROUND.into(),
+                        focused: BUTTON_FOCUSED_BACKGROUND.into(),
                     },
                     children![(
                         Name::new("Button Text"),